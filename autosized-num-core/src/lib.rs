@@ -0,0 +1,121 @@
+//! # AutoSized Int Core
+//!
+//! The width selection behind the `autosized-num` macros, as `const fn`s that
+//! downstream crates can call at compile time or at runtime.
+//!
+//! A proc-macro crate can only export macros, so the selection lives here and
+//! the macros call into it. Every width the macros pick is rounded up by
+//! `width_for_bits`, so a width computed here always matches the type the
+//! corresponding macro expands to.
+//!
+//! - `smallest_unsigned_bits`  
+//!   → The bit width of the type `auto_sized_unsigned!` picks for a value.
+//!
+//! - `smallest_signed_bits`  
+//!   → The bit width of the type `auto_sized_signed!` picks for a value.
+//!
+//! - `width_for_bits`  
+//!   → Rounds a bit count up to the width of the smallest primitive integer.
+//!
+//! - `unsigned_bits_needed` / `signed_bits_needed`  
+//!   → The exact number of bits a value needs, before rounding.
+#![no_std]
+
+/// Rounds `bits` up to the width (8, 16, 32, 64, or 128) of the smallest
+/// primitive integer type holding that many bits, or `None` past 128.
+///
+/// # Examples
+/// ```
+/// use autosized_num_core::width_for_bits;
+///
+/// assert_eq!(width_for_bits(0), Some(8));
+/// assert_eq!(width_for_bits(9), Some(16));
+/// assert_eq!(width_for_bits(64), Some(64));
+/// assert_eq!(width_for_bits(129), None);
+/// ```
+pub const fn width_for_bits(bits: u32) -> Option<u32> {
+    match bits {
+        0..=8 => Some(8),
+        9..=16 => Some(16),
+        17..=32 => Some(32),
+        33..=64 => Some(64),
+        65..=128 => Some(128),
+        _ => None,
+    }
+}
+
+/// Returns the number of bits an unsigned type needs to hold `value`, which is
+/// 0 for 0.
+///
+/// # Examples
+/// ```
+/// use autosized_num_core::unsigned_bits_needed;
+///
+/// assert_eq!(unsigned_bits_needed(0), 0);
+/// assert_eq!(unsigned_bits_needed(255), 8);
+/// assert_eq!(unsigned_bits_needed(256), 9);
+/// ```
+pub const fn unsigned_bits_needed(value: u128) -> u32 {
+    u128::BITS - value.leading_zeros()
+}
+
+/// Returns the number of bits a signed type needs to hold `value`, sign bit
+/// included.
+///
+/// Negative values are measured through `!value` rather than an absolute value,
+/// which would overflow for `i128::MIN`.
+///
+/// # Examples
+/// ```
+/// use autosized_num_core::signed_bits_needed;
+///
+/// assert_eq!(signed_bits_needed(0), 1);
+/// assert_eq!(signed_bits_needed(-128), 8);
+/// assert_eq!(signed_bits_needed(128), 9);
+/// assert_eq!(signed_bits_needed(i128::MIN), 128);
+/// ```
+pub const fn signed_bits_needed(value: i128) -> u32 {
+    let magnitude = if value < 0 { !value } else { value };
+    i128::BITS - magnitude.leading_zeros() + 1
+}
+
+/// Returns the bit width (8, 16, 32, 64, or 128) of the smallest unsigned
+/// integer type that can represent `value`.
+///
+/// # Examples
+/// ```
+/// use autosized_num_core::smallest_unsigned_bits;
+///
+/// assert_eq!(smallest_unsigned_bits(255), 8);
+/// assert_eq!(smallest_unsigned_bits(300), 16);
+///
+/// const WIDTH: u32 = smallest_unsigned_bits(u64::MAX as u128 + 1);
+/// assert_eq!(WIDTH, 128);
+/// ```
+pub const fn smallest_unsigned_bits(value: u128) -> u32 {
+    match width_for_bits(unsigned_bits_needed(value)) {
+        Some(width) => width,
+        None => u128::BITS,
+    }
+}
+
+/// Returns the bit width (8, 16, 32, 64, or 128) of the smallest signed
+/// integer type that can represent `value`.
+///
+/// The bits are counted with [`signed_bits_needed`], so `i128::MIN` needs no
+/// negation.
+///
+/// # Examples
+/// ```
+/// use autosized_num_core::smallest_signed_bits;
+///
+/// assert_eq!(smallest_signed_bits(-128), 8);
+/// assert_eq!(smallest_signed_bits(200), 16);
+/// assert_eq!(smallest_signed_bits(i128::MIN), 128);
+/// ```
+pub const fn smallest_signed_bits(value: i128) -> u32 {
+    match width_for_bits(signed_bits_needed(value)) {
+        Some(width) => width,
+        None => i128::BITS,
+    }
+}
@@ -6,17 +6,183 @@
 //! - `auto_sized_unsigned!` / `auto_sized_unsigned_val!`  
 //!   → Choose among unsigned integers (`u8`, `u16`, `u32`, `u64`, `u128`).
 //!
+//! - `auto_sized_unsigned_in!`  
+//!   → Choose among a caller-supplied list of unsigned integer types, such as
+//!   `[u16, u32, u64]`.
+//!
 //! - `auto_sized_signed!` / `auto_sized_signed_val!`  
 //!   → Choose among signed integers (`i8`, `i16`, `i32`, `i64`, `i128`).
 //!
+//! - `auto_sized_unsigned_max!` / `auto_sized_signed_min!` / `auto_sized_signed_max!`  
+//!   → Expand to the `MIN` or `MAX` constant of the chosen type, such as `u16::MAX`.
+//!
 //! - `auto_sized_int!` / `auto_sized_int_val!`  
 //!   → If the literal is negative, a signed type is chosen.  
 //!   If the literal is non‑negative, an unsigned type is chosen.  
-//!   **The accepted input range is the full `i128` domain (not `u128`).**
+//!   **Non-negative input covers the full `u128` range, negative input the `i128` range.**
+//!
+//! - `auto_sized_int_prefer_signed!` / `auto_sized_int_prefer_unsigned!`  
+//!   → Like `auto_sized_int!`, but choose which family wins for non-negative values.
+//!   `auto_sized_int!` itself prefers unsigned types.
+//!
+//! - `auto_sized_const!`  
+//!   → Expand to a `const NAME: T = value;` item with the type `auto_sized_int!` picks.
+//!
+//! - `auto_sized_cfg!`  
+//!   → Pick a literal by `cfg` predicate and type it like `auto_sized_int!`, as a value
+//!   or a `const` item.
+//!
+//! - `auto_sized_type_alias!` / `auto_sized_signed_type_alias!`  
+//!   → Expand to a `type NAME = T;` item with the type `auto_sized_unsigned!` /
+//!   `auto_sized_signed!` picks.
+//!
+//! - `auto_sized_struct!`  
+//!   → Expand to a struct whose fields written as integer literals get the type
+//!   `auto_sized_int!` picks.
+//!
+//! - `auto_sized_newtype!`  
+//!   → Expand to a tuple struct around the type `auto_sized_int!` picks, deriving
+//!   serde's traits when passed `serde`.
+//!
+//! - `assert_fits!`  
+//!   → Assert at compile time that a literal fits in a named integer type.
+//!
+//! - `auto_sized_float!` / `auto_sized_float_val!`  
+//!   → Choose between `f32` and `f64`, picking `f32` only when the literal
+//!   round-trips through it without precision loss. Integer input picks the float
+//!   that holds it exactly and is rejected when even `f64` would round it.
+//!
+//! - `auto_sized_nonzero!` / `auto_sized_nonzero_val!`  
+//!   → Choose the `core::num::NonZeroU*` counterpart of the `auto_sized_unsigned!` type,
+//!   rejecting zero.
+//!
+//! - `auto_sized_nonzero_unsigned!` / `auto_sized_nonzero_signed!` (and `_val`)  
+//!   → Choose among `core::num::NonZeroU*` / `NonZeroI*`. A literal of `0` is rejected.
+//!
+//! - `auto_sized_wrapping!` / `auto_sized_wrapping_val!`  
+//!   → Wrap the type or value `auto_sized_int!` would choose in `core::num::Wrapping`.
+//!
+//! - `auto_sized_wrapping_unsigned!` / `auto_sized_wrapping_signed!` and their `_val` forms  
+//!   → Wrap the type or value `auto_sized_unsigned!` / `auto_sized_signed!` would choose.
+//!
+//! - `auto_sized_saturating!` / `auto_sized_saturating_val!`  
+//!   → Wrap the type or value `auto_sized_int!` would choose in `core::num::Saturating`.
+//!
+//! - `auto_sized_saturating_unsigned!` / `auto_sized_saturating_signed!` and their `_val` forms  
+//!   → Wrap the type or value `auto_sized_unsigned!` / `auto_sized_signed!` would choose
+//!   in `core::num::Saturating`.
+//!
+//! - `auto_sized_atomic!` / `auto_sized_atomic_val!`  
+//!   → Choose the `core::sync::atomic` type for the literal, up to 64 bits.
+//!   `min = 32` raises the width for targets without small atomics.
+//!
+//! - `auto_sized_atomic_signed!` / `auto_sized_atomic_signed_val!`  
+//!   → Always choose an `AtomicI*` type.
+//!
+//! - `auto_sized_le_bytes!` / `auto_sized_be_bytes!`  
+//!   → Expand to the little- / big-endian byte array of the literal in the type
+//!   `auto_sized_unsigned!` would choose.
+//!
+//! - `auto_from_twos_complement!` / `auto_from_twos_complement_type!`  
+//!   → Reinterpret a bit pattern such as `0xFFF0` as a signed value of a given
+//!   width, typed with the smallest signed type that holds it.
+//!
+//! - `auto_sized_or!` / `auto_sized_or_val!`  
+//!   → Choose the smallest unsigned type that holds the bitwise OR of several literals.
+//!
+//! - `auto_sized_usize!` / `auto_sized_isize!`  
+//!   → Expand to `usize` / `isize` when the literal fits in the 16-bit minimum
+//!   pointer width (or 64 bits with `assume_64bit`), and fail to compile otherwise.
+//!
+//! - `auto_sized_bit_width!` / `auto_sized_byte_width!`  
+//!   → Expand to the bit / byte width of the type `auto_sized_unsigned!` would
+//!   choose, as a `usize` literal that also works as an array length or const
+//!   generic argument.
+//!
+//! - `auto_sized_array!`  
+//!   → Type every element of an array literal with the smallest type shared by all of them.
+//!
+//! - `auto_sized_common!`  
+//!   → Choose the smallest type, signed or unsigned, that fits every literal in a list.
+//!
+//! - `auto_sized_unsigned_all!` / `auto_sized_signed_all!`  
+//!   → Choose the smallest type that fits every literal in a comma-separated list.
+//!
+//! - `auto_sized_range!`  
+//!   → Choose the smallest type that holds every value of a range such as `-5..=200` or a `low, high` pair.
+//!
+//! - `auto_sized_flags!`  
+//!   → Choose the smallest unsigned type with at least the given number of bits.
+//!
+//! - `auto_sized_bits!` / `auto_sized_bits_val!`  
+//!   → Choose the smallest unsigned type for an N-bit field, or its largest value.
+//!
+//! - `auto_sized_for_bits!` / `auto_sized_for_bits_signed!`  
+//!   → Choose the smallest type with at least N bits, plus a sign bit for the signed form.
+//!
+//! - `auto_sized_bytes!` / `auto_sized_bytes_val!`  
+//!   → Choose the smallest unsigned type for an N-byte field, or its largest value.
+//!
+//! - `auto_sized_hex_digits!` / `auto_sized_hex_digits_val!`  
+//!   → Choose the smallest unsigned type for N hexadecimal digits, or its largest value.
+//!
+//! - `auto_sized_digits!`  
+//!   → Choose the smallest type that holds every decimal number with up to N digits.
+//!
+//! - `auto_sized_count!` / `auto_sized_count_val!`  
+//!   → Count the listed tokens or comma-separated items and choose a type that can
+//!   index them.
+//!
+//! - `auto_sized_len!` / `auto_sized_len_val!`  
+//!   → Choose the smallest unsigned type that can index a collection of the given
+//!   length, or the length typed with it.
+//!
+//! - `auto_index_type!`  
+//!   → Choose the smallest unsigned type that can index every element of an array
+//!   expression.
+//!
+//! - `auto_sized_char!` / `auto_sized_char_val!`  
+//!   → Choose the smallest unsigned type for a char literal's code point.
+//!
+//! - `auto_sized_strlen!` / `auto_sized_strlen_val!`  
+//!   → Choose the smallest unsigned type for a string literal's byte length, or
+//!   for its valid indices with `exclusive`.
+//!
+//! - `auto_sized_from_file!` / `auto_sized_from_file_val!`  
+//!   → Size a type from an integer stored in a file read at compile time.
+//!
+//! - `auto_sized_bytelen!` / `auto_sized_bytelen_file!` (and `_val`)  
+//!   → Choose the smallest unsigned type for the length of a byte string literal
+//!   or of a file read at compile time.
+//!
+//! - `auto_sized_radix!` / `auto_sized_radix_val!`  
+//!   → Size a type from a numeric string written in any radix from 2 to 36.
+//!
+//! - `auto_sized_env!` / `auto_sized_env_val!`  
+//!   → Size a type from an integer environment variable read at compile time.
+//!
+//! - `auto_sized_from_toml!` / `auto_sized_from_json!` and their `_val` forms  
+//!   → Size a type from an integer under a dotted key in a TOML or JSON file (behind
+//!   the `toml` and `json` features).
+//!
+//! - `auto_sized_option_env!` / `auto_sized_option_env_val!`  
+//!   → Like `auto_sized_env!`, but fall back to a literal when the variable is not set.
+//!
+//! - `auto_sized_pkg_version!` / `auto_sized_pkg_version_val!`  
+//!   → Size a type from the crate's major, minor, or patch version, or from all three
+//!   packed into fields of given widths.
+//!
+//! - `#[auto_size_consts]`  
+//!   → Attribute for a `mod` or `impl` block that gives every `const NAME: _ = <literal>;`
+//!   the smallest fitting integer type.
+//!
+//! - `#[derive(AutoRepr)]`  
+//!   → Derive for a fieldless enum that converts it into the smallest integer type
+//!   holding every discriminant, and checks an explicit `#[repr]` against it.
 //!
 //! ## Type vs. Value Macros
 //! - `*_unsigned!`, `*_signed!`, `*_int!` → expand to a **type**.
-//! - `*_val` variants → expand to a **value** (with an explicit `as` cast).
+//! - `*_val` variants → expand to a **value** (a literal suffixed with the chosen type).
 //!
 //! ## Examples
 //! ```rust
@@ -35,131 +201,516 @@
 //! let d = auto_sized_int_val!(-10);      // -10i8
 //! ```
 //!
+//! ## Accepted Literals
+//! Integer literals may be written in decimal, hexadecimal (`0x`), octal (`0o`),
+//! or binary (`0b`), with `_` separators anywhere after the prefix. The chosen
+//! type depends only on the numeric value, not on the base it was written in:
+//! ```rust
+//! use autosized_num::*;
+//!
+//! type H = auto_sized_unsigned!(0xFFFF);   // u16
+//! type W = auto_sized_unsigned!(0x1_0000); // u32
+//! type B = auto_sized_unsigned!(0b1_0000_0000); // u16
+//! ```
+//!
+//! A minus sign works with every base in the signed and int macros. The
+//! magnitude is read in full before negating, so the most negative value of
+//! each width stays in that width:
+//! ```rust
+//! use autosized_num::*;
+//!
+//! type A = auto_sized_signed!(-0x80);   // i8
+//! type C = auto_sized_int!(-0x8000);    // i16
+//! type D = auto_sized_signed!(-0b1000_0000); // i8
+//! ```
+//!
+//! The unsigned macros also accept a `width_from_digits` flag that sizes a
+//! hexadecimal or binary literal by the number of digits written, leading zeros
+//! included and `_` separators ignored. Odd digit counts round up to the next width:
+//! ```rust
+//! use autosized_num::*;
+//!
+//! type R = auto_sized_unsigned!(0x00FF, width_from_digits); // u16
+//! type S = auto_sized_unsigned!(0x0FFF, width_from_digits); // u16
+//! type T = auto_sized_unsigned!(0x000, width_from_digits);  // u16
+//! type M = auto_sized_unsigned!(0b0000_1010, width_from_digits); // u8
+//! ```
+//!
+//! A suffix is treated as a minimum width, so `auto_sized_unsigned!(10u32)`
+//! expands to `u32` even though `10` fits in `u8`, and `auto_sized_signed!(300i64)`
+//! expands to `i64`. The suffix must match the macro family: signed suffixes are
+//! rejected by the unsigned macros and vice versa. In `auto_sized_int!`, a signed
+//! suffix selects a signed type.
+//!
+//! A suffix too narrow for the value is a hard error rather than a silent widening:
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_unsigned!(300u8); // 300 needs u16
+//! ```
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_signed!(-200i8); // -200 needs i16
+//! ```
+//! ```compile_fail
+//! let x = autosized_num::auto_sized_int_val!(70000u16); // 70000 needs u32
+//! ```
+//!
+//! Char literals are sized by their Unicode scalar value, so
+//! `auto_sized_unsigned!('A')` expands to `u8` and `auto_sized_unsigned!('€')`
+//! to `u16`. Byte literals such as `b'\xFF'` are sized by their numeric value.
+//!
+//! Float literals are accepted when their value is an exact integer, so
+//! `auto_sized_unsigned!(1e6)` expands to `u32` while `1.5` is rejected.
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_unsigned!(1.5); // not an exact integer
+//! ```
+//!
+//! The primitive constants `u8::MAX` through `u128::MAX` and `i8::MIN`/`i8::MAX`
+//! through `i128::MIN`/`i128::MAX` are accepted in place of a literal, so
+//! `auto_sized_signed!(i32::MIN)` expands to `i32`.
+//!
+//! Constant arithmetic on those inputs is folded before sizing, using
+//! `+ - * / % << >> & | ^` with overflow and division-by-zero checks, so
+//! `auto_sized_unsigned!(1 << 20)` expands to `u32` and
+//! `auto_sized_unsigned!(u32::MAX + 1)` to `u64`. Any other operator, such as
+//! `==` or `!`, is rejected with an error naming it.
+//!
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_unsigned!(1 / 0); // division by zero
+//! ```
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_signed!(i128::MAX + 1); // overflows i128
+//! ```
+//!
+//! Named constants such as `const LIMIT: u32 = 300;` cannot be used: a proc
+//! macro runs before constants are evaluated and sees only the name, so
+//! `auto_sized_unsigned!(LIMIT)` is rejected. Write the value as a literal, or
+//! as arithmetic on literals such as `auto_sized_unsigned!(100 * 3)`.
+//!
+//! ```compile_fail
+//! const LIMIT: u32 = 300;
+//! type T = autosized_num::auto_sized_unsigned!(LIMIT); // value not visible
+//! ```
+//!
+//! `as` casts to a fixed-width integer type are accepted when they keep the
+//! value unchanged, so `auto_sized_unsigned!(u8::MAX as u128 + 1)` expands to
+//! `u16`. A cast that would truncate is rejected instead of wrapping:
+//!
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_unsigned!(300 as u8); // truncates to 44
+//! ```
+//!
+//! A single leading `+` before an integer literal is accepted and dropped, so
+//! `auto_sized_int_val!(+10)` expands to `10u8`; `+-10` and `++10` are rejected.
+//!
+//! ```compile_fail
+//! type T = autosized_num::auto_sized_signed!(+-10);
+//! ```
+//!
 //! ## Intended Use Cases
 //! - Binary parsing or serialization where minimal integer widths matter.
 //! - Defining constants or generic parameters with the smallest fitting type.
 //! - Compile‑time tests ensuring literals map to the expected integer type.
 //!
+//! ## Using the selection without macros
+//! A proc-macro crate can only export macros, so the width selection lives in
+//! the companion `autosized-num-core` crate as `const fn`s, and the macros call
+//! into it. `smallest_unsigned_bits(300)` returns `16`, matching
+//! `auto_sized_unsigned!(300)`, and `smallest_signed_bits` does the same for
+//! `auto_sized_signed!`. `width_for_bits` rounds a bit count up the same way
+//! every macro does. Depend on `autosized-num-core` directly to compute widths
+//! at runtime or in `const` items.
+//!
 //! ## Notes
-//! - `auto_sized_int!` and `auto_sized_int_val!` accept the full `i128` range.
-//! - Non‑integer inputs will trigger a `compile_error!`.
+//! - `auto_sized_int!` and `auto_sized_int_val!` accept non-negative values up to
+//!   `u128::MAX` and negative values down to
+//!   `-170141183460469231731687303715884105728` (`i128::MIN`).
+//! - Inputs other than integer, char, or byte literals will trigger a `compile_error!`.
+//! - Type macros emit primitives as `::core::primitive::u16` and so on, so a local
+//!   alias such as `type u16 = String;` does not change the expanded type.
+//! - Input forwarded from a `macro_rules!` `$x:expr` or `$x:literal` fragment arrives in
+//!   invisible groups, which are unwrapped at any depth before parsing.
+//! - Value macros given a plain literal re-emit its digits as written, so
+//!   `auto_sized_unsigned_val!(1_000_000)` expands to `1_000_000u32` and hex or
+//!   binary input keeps its radix.
+//! - Value macros emit suffixed literals rather than `as` casts, so their
+//!   expansions pass `clippy::all`, `clippy::cast_lossless`, and
+//!   `clippy::unnecessary_cast` without an `allow` at the call site.
+
+use core::fmt::Display;
 
+use autosized_num_core::{
+    signed_bits_needed, smallest_signed_bits, smallest_unsigned_bits, unsigned_bits_needed,
+    width_for_bits,
+};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{LitInt, parse_macro_input};
+use proc_macro2::{Ident, Literal, Span};
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    BinOp, Data, DeriveInput, Expr, ExprArray, ExprBinary, ExprCast, ExprGroup, ExprLit, ExprParen,
+    ExprPath, ExprRange, ExprUnary, Fields, ImplItem, ImplItemConst, Item, ItemConst, ItemImpl,
+    ItemMod, Lit, LitByteStr, LitChar, LitFloat, LitInt, LitStr, RangeLimits, Token, Type, UnOp,
+    Visibility, parse_macro_input, parse_quote,
+};
 
 /// Returns the smallest unsigned integer type (`u8`, `u16`, `u32`, `u64`, or `u128`)
 /// that can represent the given literal.
 ///
+/// Passing `width_from_digits` after a hexadecimal or binary literal sizes the type
+/// by the number of digits written (leading zeros included) instead of by its value.
+///
+/// An unsigned suffix such as `10u32` is honored as a minimum width.
+///
+/// Passing `min = u16` (up to `u128`) never picks a narrower type, and passing
+/// `max = u64` (or `u8`, `u16`, `u32`) caps the candidate types, turning a value
+/// that would need a wider type into a compile error. A value that outgrows the
+/// floor still gets the wider type, and a suffix combined with `min` takes
+/// whichever is wider. A `min` wider than `max` is rejected.
+///
+/// Passing `native` prefers `usize` whenever it is at least as wide as the chosen
+/// type. The expansion selects per target with `core::cfg_select!`, so the same
+/// input gives `usize` on 64-bit targets and `u64` on 32-bit ones, and needs
+/// Rust 1.95 or later. Values that need `u128` are never widened to `usize`.
+/// With `max`, `usize` is only chosen on targets whose pointers fit within the
+/// cap, and a `=> u16` hint cannot be combined with `native`.
+///
+/// Writing `=> u16` directly after the literal asserts the choice: the macro
+/// still expands to the chosen type, but fails to compile if that type is not
+/// the hinted one, which catches a constant growing past an assumed width.
+///
 /// # Examples
 /// ```
-/// use autosized_num::auto_sized_unsigned;
+/// use autosized_num::{auto_sized_unsigned, auto_sized_unsigned_val};
 ///
 /// type T = auto_sized_unsigned!(300);
 /// // expands to: type T = u16;
+///
+/// type R = auto_sized_unsigned!(0x00FF, width_from_digits);
+/// // expands to: type R = u16;
+///
+/// type M = auto_sized_unsigned!(0b0000_1010, width_from_digits);
+/// // expands to: type M = u8;
+///
+/// type F = auto_sized_unsigned!(10u32);
+/// // expands to: type F = u32;
+///
+/// type C = auto_sized_unsigned!(70000, max = u64);
+/// // expands to: type C = u32;
+///
+/// type A = auto_sized_unsigned!(10, min = u16);
+/// // expands to: type A = u16;
+///
+/// type H = auto_sized_unsigned!(300 => u16);
+/// // expands to: type H = u16;
+///
+/// type N = auto_sized_unsigned!(5_000_000_000, native);
+/// // expands to: usize on 64-bit targets, u64 elsewhere
+///
+/// const TIMEOUT_MS: auto_sized_unsigned!(5000) = 5000;
+/// static RETRIES: auto_sized_unsigned!(300) = auto_sized_unsigned_val!(300);
+/// // the type macros work in `const` and `static` annotations too
+/// ```
+/// ```compile_fail
+/// type C = autosized_num::auto_sized_unsigned!(u64::MAX + 1, max = u64); // needs u128
+/// ```
+/// ```compile_fail
+/// type H = autosized_num::auto_sized_unsigned!(70000 => u16); // chooses u32
 /// ```
 #[proc_macro]
 pub fn auto_sized_unsigned(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<u128>() {
+    if input.is_empty() {
+        return missing_argument("auto_sized_unsigned!");
+    }
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, width) = match native_literal_width(&input) {
         Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_unsign! only accepts integer literals");
-            }
-            .into();
-        }
+        Err(err) => return spanned_error("auto_sized_unsigned!", err),
     };
+    let ty = primitive(format!("u{width}"));
 
-    pick_unsigned_type(value).into()
+    if input.native.is_some() {
+        native_select(width, input.max.as_ref(), primitive("usize"), ty).into()
+    } else {
+        ty.into()
+    }
 }
 
-/// Returns the given literal as a value, cast to the smallest unsigned integer type
-/// that can represent it.
+/// Returns the given literal as a value, suffixed with the smallest unsigned integer
+/// type that can represent it.
+///
+/// Accepts the same options as [`auto_sized_unsigned!`], including `native`,
+/// which suffixes the value with `usize` where the type macro picks it.
 ///
 /// # Examples
 /// ```
 /// use autosized_num::auto_sized_unsigned_val;
 ///
 /// let x = auto_sized_unsigned_val!(300);
-/// // expands to: 300 as u16
+/// // expands to: 300u16
+///
+/// let y = auto_sized_unsigned_val!(1_000_000);
+/// // expands to: 1_000_000u32
+///
+/// let a = auto_sized_unsigned_val!(b'A');
+/// // expands to: 65u8
+///
+/// let len = auto_sized_unsigned_val!(70_000, native);
+/// // expands to: 70_000usize on 32- and 64-bit targets, 70_000u32 elsewhere
 /// ```
 #[proc_macro]
 pub fn auto_sized_unsigned_val(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<u128>() {
+    if input.is_empty() {
+        return missing_argument("auto_sized_unsigned_val!");
+    }
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (value, width) = match native_literal_width(&input) {
         Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_unsign_val! only accepts integer literals");
-            }
-            .into();
-        }
+        Err(err) => return spanned_error("auto_sized_unsigned_val!", err),
+    };
+    let fixed = match unsigned_suffixed(value, &primitive(format!("u{width}"))) {
+        Ok(v) => source_literal(v, &input.expr),
+        Err(err) => return spanned_error("auto_sized_unsigned_val!", call_site_error(err)),
+    };
+
+    if input.native.is_some() {
+        let native = source_literal(native_literal(value, "usize"), &input.expr);
+        native_select(
+            width,
+            input.max.as_ref(),
+            quote! { #native },
+            quote! { #fixed },
+        )
+        .into()
+    } else {
+        quote! { #fixed }.into()
+    }
+}
+
+/// Returns the `MAX` constant of the type [`auto_sized_unsigned!`] would choose
+/// for the given literal, as a typed expression.
+///
+/// Accepts the same options as [`auto_sized_unsigned!`], including `native`,
+/// which gives `usize::MAX` where the type macro picks `usize`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_unsigned_max;
+///
+/// let limit = auto_sized_unsigned_max!(300);
+/// // expands to: u16::MAX
+/// assert!(70_000 > u32::from(limit));
+///
+/// let cap = auto_sized_unsigned_max!(300, native);
+/// // expands to: usize::MAX on targets with at least 16-bit pointers
+/// assert_eq!(cap, usize::MAX);
+/// ```
+#[proc_macro]
+pub fn auto_sized_unsigned_max(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, width) = match native_literal_width(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_unsigned_max!", err),
     };
+    let ty = primitive(format!("u{width}"));
 
-    let ty = pick_unsigned_type(value);
+    if input.native.is_some() {
+        let native = primitive("usize");
+        native_select(
+            width,
+            input.max.as_ref(),
+            quote! { #native::MAX },
+            quote! { #ty::MAX },
+        )
+        .into()
+    } else {
+        quote! { #ty::MAX }.into()
+    }
+}
 
-    quote! { #value as #ty }.into()
+/// Returns the smallest type from a bracketed list of unsigned integer types
+/// that can represent the given literal.
+///
+/// The list may name any of `u8`, `u16`, `u32`, `u64`, and `u128` in any order.
+/// The literal accepts the same forms as [`auto_sized_unsigned!`], and a literal
+/// that fits none of the listed types is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_unsigned_in;
+///
+/// type T1 = auto_sized_unsigned_in!([u16, u32, u64], 10); // expands to u16
+/// type T2 = auto_sized_unsigned_in!([u16, u64], 70000);   // expands to u64
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_unsigned_in!([u8, u16], 70000); // needs u32
+/// ```
+#[proc_macro]
+pub fn auto_sized_unsigned_in(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInInput);
+    match unsigned_in_type(&input) {
+        Ok(ty) => ty.into(),
+        Err(err) => spanned_error("auto_sized_unsigned_in!", err),
+    }
 }
 
 /// Returns the smallest signed integer type (`i8`, `i16`, `i32`, `i64`, or `i128`)
 /// that can represent the given literal.
 ///
+/// A signed suffix such as `300i64` is honored as a minimum width.
+///
+/// Passing `native` prefers `isize` wherever it is at least as wide as the
+/// chosen type and within any `max` cap, like the `native` option of
+/// [`auto_sized_unsigned!`].
+///
+/// Passing `min = i32` (up to `i128`) never picks a narrower type, and passing
+/// `max = i32` (or `i8`, `i16`, `i64`) turns a value that would need a wider
+/// type into a compile error, as with [`auto_sized_unsigned!`].
+///
 /// # Examples
 /// ```
 /// use autosized_num::auto_sized_signed;
 ///
 /// type T = auto_sized_signed!(-200);
 /// // expands to: type T = i16;
+///
+/// type N = auto_sized_signed!(-70_000, native);
+/// // expands to: isize on 32- and 64-bit targets, i32 elsewhere
+///
+/// type C = auto_sized_signed!(-70_000, max = i32);
+/// // expands to: type C = i32;
+///
+/// type A = auto_sized_signed!(-200, min = i32);
+/// // expands to: type A = i32;
+/// ```
+/// ```compile_fail
+/// type C = autosized_num::auto_sized_signed!(-3_000_000_000, max = i32); // needs i64
 /// ```
 #[proc_macro]
 pub fn auto_sized_signed(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<i128>() {
+    if input.is_empty() {
+        return missing_argument("auto_sized_signed!");
+    }
+    let input = parse_macro_input!(input as SignedInput);
+    let (_, ty) = match capped_signed_type(&input) {
         Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_sign! only accepts integer literals");
-            }
-            .into();
-        }
+        Err(err) => return spanned_error("auto_sized_signed!", err),
     };
 
-    pick_signed_type(value).into()
+    if input.native {
+        native_select(
+            primitive_width(&ty),
+            input.max.as_ref(),
+            primitive("isize"),
+            ty,
+        )
+        .into()
+    } else {
+        ty.into()
+    }
 }
 
-/// Returns the given literal as a value, cast to the smallest signed integer type
-/// that can represent it.
+/// Returns the given literal as a value, suffixed with the smallest signed integer
+/// type that can represent it.
+///
+/// Accepts the same options as [`auto_sized_signed!`].
 ///
 /// # Examples
 /// ```
 /// use autosized_num::auto_sized_signed_val;
 ///
 /// let y = auto_sized_signed_val!(-200);
-/// // expands to: -200 as i16
+/// // expands to: -200i16
+///
+/// let n = auto_sized_signed_val!(-200, native);
+/// // expands to: -200isize on targets with at least 16-bit pointers
 /// ```
 #[proc_macro]
 pub fn auto_sized_signed_val(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<i128>() {
+    if input.is_empty() {
+        return missing_argument("auto_sized_signed_val!");
+    }
+    let input = parse_macro_input!(input as SignedInput);
+    let (value, ty) = match capped_signed_type(&input) {
         Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_sign_val! only accepts integer literals");
-            }
-            .into();
-        }
+        Err(err) => return spanned_error("auto_sized_signed_val!", err),
+    };
+    let fixed = match signed_suffixed(value, &ty) {
+        Ok(v) => source_literal(v, &input.expr),
+        Err(err) => return spanned_error("auto_sized_signed_val!", call_site_error(err)),
+    };
+
+    if input.native {
+        let native = source_literal(native_literal(value, "isize"), &input.expr);
+        let width = primitive_width(&ty);
+        native_select(
+            width,
+            input.max.as_ref(),
+            quote! { #native },
+            quote! { #fixed },
+        )
+        .into()
+    } else {
+        quote! { #fixed }.into()
+    }
+}
+
+/// Returns the `MIN` constant of the type [`auto_sized_signed!`] would choose
+/// for the given literal, as a typed expression.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_signed_min;
+///
+/// let floor = auto_sized_signed_min!(-200);
+/// // expands to: i16::MIN
+/// ```
+#[proc_macro]
+pub fn auto_sized_signed_min(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match signed_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_signed_min!", err),
     };
 
-    let ty = pick_signed_type(value);
+    quote! { #ty::MIN }.into()
+}
+
+/// Returns the `MAX` constant of the type [`auto_sized_signed!`] would choose
+/// for the given literal, as a typed expression.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_signed_max;
+///
+/// let ceiling = auto_sized_signed_max!(-200);
+/// // expands to: i16::MAX
+/// ```
+#[proc_macro]
+pub fn auto_sized_signed_max(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match signed_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_signed_max!", err),
+    };
 
-    quote! { #value as #ty }.into()
+    quote! { #ty::MAX }.into()
 }
 
 /// Returns the smallest integer type (signed or unsigned) that can represent the given literal.
 /// - If the literal is negative, a signed type is chosen.
 /// - If the literal is non-negative, an unsigned type is chosen.
-/// - The accepted range of input is the full `i128` range (not `u128`).
+/// - Non-negative input may use the full `u128` range; negative input the `i128` range.
+/// - A suffix is honored as a minimum width; a signed suffix selects a signed type.
+/// - A leading `+`, as in `+200`, forces an unsigned type.
+/// - With `ptr_width = 16`, `32`, or `64`, a type exactly as wide as that pointer
+///   width becomes `usize` or `isize`. Narrower types and suffixed literals are
+///   left alone.
+/// - With `max = u32` or `max = i32` (any of `u8` through `u64` or `i8` through
+///   `i64`), a value whose type is wider than that is a compile error; only the
+///   width of the named type matters, not its signedness.
+/// - With `min = u32` or `min = i32` (up to `u128` or `i128`), a narrower type is
+///   widened to that many bits, keeping the family the value chose.
 ///
 /// # Examples
 /// ```
@@ -168,88 +719,5289 @@ pub fn auto_sized_signed_val(input: TokenStream) -> TokenStream {
 /// type T1 = auto_sized_int!(10);   // expands to u8
 /// type T2 = auto_sized_int!(-10);  // expands to i8
 /// type T3 = auto_sized_int!(12345678901234567890); // expands to u64/u128 depending on value
+/// type T4 = auto_sized_int!(-70000, ptr_width = 32); // expands to isize
+/// type T5 = auto_sized_int!(-70000, max = u32); // expands to i32
+/// type T6 = auto_sized_int!(-10, min = u32);    // expands to i32
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_int!(5_000_000_000, max = u32); // needs u64
 /// ```
 #[proc_macro]
 pub fn auto_sized_int(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<i128>() {
-        Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_int! only accepts integer literals");
-            }
-            .into();
-        }
+    if input.is_empty() {
+        return missing_argument("auto_sized_int!");
+    }
+    let input = parse_macro_input!(input as IntInput);
+    let (_, ty) = match capped_int_type(&input) {
+        Ok(v) => pointer_int_type(&input, v),
+        Err(err) => return spanned_error("auto_sized_int!", err),
     };
 
-    if value < 0 {
-        pick_signed_type(value)
-    } else {
-        pick_unsigned_type(value as u128)
-    }
-    .into()
+    ty.into()
 }
 
-/// Returns the given literal as a value, cast to the smallest integer type
+/// Returns the given literal as a value, suffixed with the smallest integer type
 /// (signed or unsigned) that can represent it.
 /// - If the literal is negative, a signed type is chosen.
 /// - If the literal is non-negative, an unsigned type is chosen.
-/// - The accepted range of input is the full `i128` range (not `u128`).
+/// - Non-negative input may use the full `u128` range; negative input the `i128` range.
+/// - Accepts the `ptr_width`, `min`, and `max` options of [`auto_sized_int!`].
 ///
 /// # Examples
 /// ```
 /// use autosized_num::auto_sized_int_val;
 ///
-/// let a = auto_sized_int_val!(10);   // expands to 10 as u8
-/// let b = auto_sized_int_val!(-10);  // expands to -10 as i8
-/// let c = auto_sized_int_val!(12345678901234567890); // expands to value as u64/u128
+/// let a = auto_sized_int_val!(10);   // expands to 10u8
+/// let b = auto_sized_int_val!(-10);  // expands to -10i8
+/// let c = auto_sized_int_val!(12345678901234567890); // expands to 12345678901234567890u64
+/// let d = auto_sized_int_val!(70000, ptr_width = 32); // expands to 70000usize
 /// ```
 #[proc_macro]
 pub fn auto_sized_int_val(input: TokenStream) -> TokenStream {
-    let lit = parse_macro_input!(input as LitInt);
-    let value = match lit.base10_parse::<i128>() {
+    if input.is_empty() {
+        return missing_argument("auto_sized_int_val!");
+    }
+    let input = parse_macro_input!(input as IntInput);
+    let (value, _) = match capped_int_type(&input) {
+        Ok(v) => pointer_int_type(&input, v),
+        Err(err) => return spanned_error("auto_sized_int_val!", err),
+    };
+
+    let value = source_literal(value, &input.int.expr);
+    quote! { #value }.into()
+}
+
+/// Like [`auto_sized_int!`], but picks the smallest signed type for non-negative
+/// values as well, e.g. for interop with C APIs that take signed integers.
+///
+/// Values that only fit in an unsigned type, such as those above `i128::MAX` or
+/// with an unsigned suffix, still expand to the type [`auto_sized_int!`] chooses,
+/// as do values written with a leading `+`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_int_prefer_signed;
+///
+/// type T1 = auto_sized_int_prefer_signed!(200);  // expands to i16
+/// type T2 = auto_sized_int_prefer_signed!(-200); // expands to i16
+/// ```
+#[proc_macro]
+pub fn auto_sized_int_prefer_signed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as IntExpr);
+    let ty = match signed_type(&input.expr) {
+        Ok((_, ty)) if !input.plus => ty,
+        _ => match hinted_int_type(&input) {
+            Ok((_, ty)) => ty,
+            Err(err) => return spanned_error("auto_sized_int_prefer_signed!", err),
+        },
+    };
+
+    ty.into()
+}
+
+/// Like [`auto_sized_int!`], spelled out for call sites that want to state the
+/// preference: non-negative values pick the smallest unsigned type and negative
+/// values the smallest signed type.
+///
+/// [`auto_sized_int!`] itself remains unsigned-preferring.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_int_prefer_unsigned;
+///
+/// type T1 = auto_sized_int_prefer_unsigned!(200);  // expands to u8
+/// type T2 = auto_sized_int_prefer_unsigned!(-200); // expands to i16
+/// ```
+#[proc_macro]
+pub fn auto_sized_int_prefer_unsigned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match hinted_int_type(&input) {
         Ok(v) => v,
-        Err(_) => {
-            return quote! {
-                compile_error!("auto_sized_int_val! only accepts integer literals");
-            }
-            .into();
-        }
+        Err(err) => return spanned_error("auto_sized_int_prefer_unsigned!", err),
     };
 
-    let ty = if value < 0 {
-        pick_signed_type(value)
-    } else {
-        pick_unsigned_type(value as u128)
+    ty.into()
+}
+
+/// Expands to a `const` item whose type is the one [`auto_sized_int!`] would
+/// choose for the given literal, so the type is picked once alongside the name.
+///
+/// The name may be preceded by a visibility such as `pub`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_const;
+///
+/// auto_sized_const!(MAX_FRAME, 300);
+/// // expands to: const MAX_FRAME: u16 = 300u16;
+///
+/// auto_sized_const!(pub OFFSET, -200);
+/// // expands to: pub const OFFSET: i16 = -200i16;
+/// ```
+#[proc_macro]
+pub fn auto_sized_const(input: TokenStream) -> TokenStream {
+    let ConstInput { vis, name, expr } = parse_macro_input!(input as ConstInput);
+    let (value, ty) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_const!", err),
     };
+    let value = source_literal(value, &expr);
 
-    quote! { #value as #ty }.into()
+    quote! { #vis const #name: #ty = #value; }.into()
 }
 
-fn pick_unsigned_type(value: u128) -> proc_macro2::TokenStream {
-    if value <= u8::MAX as u128 {
-        quote! { u8 }
-    } else if value <= u16::MAX as u128 {
-        quote! { u16 }
-    } else if value <= u32::MAX as u128 {
-        quote! { u32 }
-    } else if value <= u64::MAX as u128 {
-        quote! { u64 }
-    } else {
-        quote! { u128 }
-    }
+/// Expands to a `type NAME = T;` item, where `T` is the type
+/// [`auto_sized_unsigned!`] would choose for the literal.
+///
+/// A visibility may precede the name, as with [`auto_sized_const!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_type_alias;
+///
+/// auto_sized_type_alias!(SampleT, 70000);
+/// // expands to: type SampleT = u32;
+///
+/// auto_sized_type_alias!(pub Channel, 200);
+/// // expands to: pub type Channel = u8;
+/// ```
+#[proc_macro]
+pub fn auto_sized_type_alias(input: TokenStream) -> TokenStream {
+    let ConstInput { vis, name, expr } = parse_macro_input!(input as ConstInput);
+    let (_, ty) = match unsigned_int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_type_alias!", err),
+    };
+
+    quote! { #vis type #name = #ty; }.into()
 }
 
-fn pick_signed_type(value: i128) -> proc_macro2::TokenStream {
-    if value >= i8::MIN as i128 && value <= i8::MAX as i128 {
-        quote! { i8 }
-    } else if value >= i16::MIN as i128 && value <= i16::MAX as i128 {
-        quote! { i16 }
-    } else if value >= i32::MIN as i128 && value <= i32::MAX as i128 {
-        quote! { i32 }
-    } else if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
-        quote! { i64 }
-    } else {
-        quote! { i128 }
-    }
+/// Expands to a `type NAME = T;` item, where `T` is the type
+/// [`auto_sized_signed!`] would choose for the literal, so the sign is taken
+/// into account.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_signed_type_alias;
+///
+/// auto_sized_signed_type_alias!(Offset, -200);
+/// // expands to: type Offset = i16;
+///
+/// auto_sized_signed_type_alias!(pub Delta, 100);
+/// // expands to: pub type Delta = i8;
+/// ```
+#[proc_macro]
+pub fn auto_sized_signed_type_alias(input: TokenStream) -> TokenStream {
+    let ConstInput { vis, name, expr } = parse_macro_input!(input as ConstInput);
+    let (_, ty) = match signed_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_signed_type_alias!", err),
+    };
+
+    quote! { #vis type #name = #ty; }.into()
+}
+
+/// Expands to a tuple struct `NAME(T)`, where `T` is the type [`auto_sized_int!`]
+/// would choose for the literal.
+///
+/// Passing `serde` after the literal also derives `serde::Serialize` and
+/// `serde::Deserialize`. The derives name `::serde`, so the calling crate must
+/// depend on `serde` with its `derive` feature; a proc-macro crate cannot
+/// re-export it. Without the argument, the struct has no derives.
+///
+/// A visibility before the name applies to both the struct and its field.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_newtype;
+///
+/// auto_sized_newtype!(pub Id, 300);
+/// // expands to: pub struct Id(pub u16);
+///
+/// auto_sized_newtype!(pub Key, 70000, serde);
+/// // expands to: #[derive(::serde::Serialize, ::serde::Deserialize)] pub struct Key(pub u32);
+///
+/// let id = Id(42);
+/// ```
+#[proc_macro]
+pub fn auto_sized_newtype(input: TokenStream) -> TokenStream {
+    let NewtypeInput {
+        vis,
+        name,
+        expr,
+        serde,
+    } = parse_macro_input!(input as NewtypeInput);
+    let (_, ty) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_newtype!", err),
+    };
+    let derives = if serde {
+        quote! { #[derive(::serde::Serialize, ::serde::Deserialize)] }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #derives
+        #vis struct #name(#vis #ty);
+    }
+    .into()
+}
+
+/// Expands to a struct whose integer fields are sized from their maximum
+/// values.
+///
+/// A field whose type is written as an integer literal, such as `b: 70000`, gets
+/// the type [`auto_sized_int!`] would choose for it. Any other field type is
+/// kept as written, along with attributes, visibilities, and generics.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_struct;
+///
+/// auto_sized_struct! {
+///     #[derive(Debug, Default)]
+///     pub struct Packet {
+///         pub a: 255,
+///         b: 70000,
+///         skew: -200,
+///         tag: &'static str,
+///     }
+/// }
+/// // expands to: pub struct Packet { pub a: u8, b: u32, skew: i16, tag: &'static str }
+///
+/// let packet = Packet { a: 255, b: 70000, skew: -200, tag: "ping" };
+/// ```
+#[proc_macro]
+pub fn auto_sized_struct(input: TokenStream) -> TokenStream {
+    let StructInput {
+        attrs,
+        vis,
+        name,
+        generics,
+        fields,
+    } = parse_macro_input!(input as StructInput);
+    let mut sized = Vec::with_capacity(fields.len());
+    for (attrs, vis, field, ty) in fields {
+        let ty = match ty {
+            StructFieldType::Int(expr) => match int_type(&expr) {
+                Ok((_, ty)) => ty,
+                Err(err) => return spanned_error("auto_sized_struct!", err),
+            },
+            StructFieldType::Type(ty) => ty.into_token_stream(),
+        };
+        sized.push(quote! { #(#attrs)* #vis #field: #ty });
+    }
+    let where_clause = &generics.where_clause;
+
+    quote! {
+        #(#attrs)*
+        #vis struct #name #generics #where_clause {
+            #(#sized,)*
+        }
+    }
+    .into()
+}
+
+/// Chooses a literal by `cfg` predicate and types it like [`auto_sized_int!`],
+/// so the same value can be `u16` on one target and `u32` on another.
+///
+/// Each `cfg(predicate) => literal` arm is sized on its own, and the expansion
+/// leaves it to the compiler to keep the arm whose predicate is active. Arms are
+/// tried in order, so the first active one wins even when several predicates
+/// overlap. A final `_ => literal` arm applies when none is active; without one,
+/// a configuration that matches no arm fails to compile.
+///
+/// The macro expands to the chosen value. Starting the input with `NAME,` (with
+/// an optional visibility) declares a constant of that name instead, like
+/// [`auto_sized_const!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_cfg;
+///
+/// let size = auto_sized_cfg! { cfg(target_os = "none") => 512, _ => 65536 };
+/// // expands to: 65536u32 on hosted targets and 512u16 on bare metal
+///
+/// auto_sized_cfg! { pub BUFFER_SIZE, cfg(target_os = "none") => 512, _ => 65536 }
+/// // expands to: pub const BUFFER_SIZE: u32 = 65536u32; on hosted targets
+/// ```
+/// ```compile_fail
+/// let size = autosized_num::auto_sized_cfg! { cfg(any()) => 512 }; // no arm is active
+/// ```
+#[proc_macro]
+pub fn auto_sized_cfg(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as CfgInput);
+    match cfg_consts(&input) {
+        Ok(consts) => match &input.item {
+            Some(_) => consts.into(),
+            None => quote! {
+                {
+                    #consts
+                    VALUE
+                }
+            }
+            .into(),
+        },
+        Err(err) => spanned_error("auto_sized_cfg!", err),
+    }
+}
+
+/// Asserts at compile time that the given literal fits in the named integer type,
+/// expanding to nothing when it does.
+///
+/// The type must be one of `u8` through `u128` or `i8` through `i128`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::assert_fits;
+///
+/// assert_fits!(300, u16);
+/// assert_fits!(-128, i8);
+/// ```
+/// ```compile_fail
+/// autosized_num::assert_fits!(300, u8); // error: 300 does not fit in u8
+/// ```
+#[proc_macro]
+pub fn assert_fits(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as FitsInput);
+    match check_fits(&input) {
+        Ok(()) => TokenStream::new(),
+        Err(err) => spanned_error("assert_fits!", err),
+    }
+}
+
+/// Returns the smallest floating-point type (`f32` or `f64`) that can represent
+/// the given literal without precision loss.
+///
+/// A float literal is considered representable as `f32` when it survives a round
+/// trip through `f32` unchanged. Literals that overflow to infinity are rejected.
+/// An `f64` suffix is honored as a minimum width, and an `f32` suffix on a value
+/// that `f32` would round is rejected.
+///
+/// An integer expression is sized by its significant bits instead, counted
+/// exactly from the highest to the lowest set bit of its magnitude: up to 24
+/// picks `f32` and up to 53 picks `f64`. An integer neither holds exactly, such
+/// as `2^53 + 1`, is rejected rather than rounded. Integer suffixes such as
+/// `16_777_216u64` are rejected, since they name no float type.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_float;
+///
+/// type T1 = auto_sized_float!(1.5); // expands to f32
+/// type T2 = auto_sized_float!(0.1); // expands to f64
+/// type T5 = auto_sized_float!(1.5f64); // expands to f64
+/// type T3 = auto_sized_float!(16_777_216); // expands to f32
+/// type T4 = auto_sized_float!(16_777_217); // expands to f64
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_float!(9_007_199_254_740_993); // 2^53 + 1
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_float!(0.1f32); // rounds in f32
+/// ```
+#[proc_macro]
+pub fn auto_sized_float(input: TokenStream) -> TokenStream {
+    let ty = match parse_macro_input!(input as FloatInput) {
+        FloatInput::Float(lit) => float_literal_type(&lit),
+        FloatInput::Int(expr) => int_float_type(&expr).map(|(_, ty)| ty),
+    };
+    match ty {
+        Ok(ty) => primitive(ty).into(),
+        Err(err) => spanned_error("auto_sized_float!", err),
+    }
+}
+
+/// Returns the given literal as a value, suffixed with the smallest floating-point
+/// type (`f32` or `f64`) that can represent it without precision loss.
+///
+/// A float suffix follows the rules of [`auto_sized_float!`], so `0.5f64` stays
+/// `f64` and `0.1f32` is rejected.
+///
+/// Integer input is sized like [`auto_sized_float!`] and emitted in decimal with
+/// the float suffix rather than as an `as` cast, so the value also works in
+/// `const` items and patterns.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_float_val;
+///
+/// let x = auto_sized_float_val!(1.5); // expands to: 1.5f32
+/// let y = auto_sized_float_val!(0.1); // expands to: 0.1f64
+/// let z = auto_sized_float_val!(-16_777_217); // expands to: -16777217f64
+/// ```
+#[proc_macro]
+pub fn auto_sized_float_val(input: TokenStream) -> TokenStream {
+    let lit = match parse_macro_input!(input as FloatInput) {
+        FloatInput::Float(lit) => lit,
+        FloatInput::Int(expr) => {
+            return match int_float_type(&expr) {
+                Ok((value, ty)) => {
+                    let value = format!("{value}{ty}")
+                        .parse()
+                        .expect("float-suffixed integer literal");
+                    let value = respan(value, source_span(&expr));
+                    quote! { #value }.into()
+                }
+                Err(err) => spanned_error("auto_sized_float_val!", err),
+            };
+        }
+    };
+    let ty = match float_literal_type(&lit) {
+        Ok(ty) => ty,
+        Err(err) => return spanned_error("auto_sized_float_val!", err),
+    };
+
+    let digits = lit.base10_digits();
+    match format!("{digits}{ty}").parse::<Literal>() {
+        Ok(value) => {
+            let value = respan(value, lit.span());
+            quote! { #value }.into()
+        }
+        Err(_) => spanned_error(
+            "auto_sized_float_val!",
+            received_error(&lit, "only accepts float literals"),
+        ),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can represent every one of the
+/// given literals.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_unsigned_all;
+///
+/// type T = auto_sized_unsigned_all!(10, 300, 70000);
+/// // expands to: type T = u32;
+/// ```
+#[proc_macro]
+pub fn auto_sized_unsigned_all(input: TokenStream) -> TokenStream {
+    let lits = parse_macro_input!(input with Punctuated::<LitInt, Token![,]>::parse_terminated);
+    let max = match max_unsigned_literal(&lits) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_unsigned_all!", err),
+    };
+
+    pick_unsigned_type(max).into()
+}
+
+/// Returns the smallest signed integer type that can represent every one of the
+/// given literals, considering both the most negative and most positive entries.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_signed_all;
+///
+/// type T = auto_sized_signed_all!(-200, 10, 100);
+/// // expands to: type T = i16;
+/// ```
+#[proc_macro]
+pub fn auto_sized_signed_all(input: TokenStream) -> TokenStream {
+    let exprs = parse_macro_input!(input with Punctuated::<Expr, Token![,]>::parse_terminated);
+    let (min, max) = match signed_literal_bounds(&exprs) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_signed_all!", err),
+    };
+
+    pick_signed_width(signed_bits_needed(min).max(signed_bits_needed(max))).into()
+}
+
+/// Returns the smallest integer type that can represent every one of the given
+/// literals, picking signedness like [`auto_sized_int!`].
+///
+/// When every value is non-negative an unsigned type is chosen; otherwise the
+/// smallest signed type covering both the lowest and the highest value is used,
+/// so `-1` together with `40000` needs `i32` rather than `u16`. A single element
+/// behaves like [`auto_sized_int!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_common;
+///
+/// type T1 = auto_sized_common!(3, 900, 70000); // expands to u32
+/// type T2 = auto_sized_common!(-1, 200);       // expands to i16
+/// type T3 = auto_sized_common!(-1, 40000);     // expands to i32
+/// ```
+#[proc_macro]
+pub fn auto_sized_common(input: TokenStream) -> TokenStream {
+    let exprs = parse_macro_input!(input with Punctuated::<Expr, Token![,]>::parse_terminated);
+    match common_type(&exprs) {
+        Ok(ty) => ty.into(),
+        Err(err) => spanned_error("auto_sized_common!", err),
+    }
+}
+
+/// Expands a bracketed list of integer literals to an array whose elements all
+/// carry the type [`auto_sized_common!`] would choose for the list.
+///
+/// Useful for compile-time tables: the element type is sized to the extreme
+/// values, and any negative element makes the whole array signed.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_array;
+///
+/// let table = auto_sized_array!([1, 2, 300, 70000]);
+/// // expands to: [1u32, 2u32, 300u32, 70000u32]
+/// let offsets = auto_sized_array!([-1, 0, 100]);
+/// // expands to: [-1i8, 0i8, 100i8]
+/// ```
+#[proc_macro]
+pub fn auto_sized_array(input: TokenStream) -> TokenStream {
+    let array = parse_macro_input!(input as ExprArray);
+    match array_literals(&array) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => spanned_error("auto_sized_array!", err),
+    }
+}
+
+/// Returns the smallest integer type that can hold every value in the given range.
+///
+/// The bounds are given either as a range expression, where both `..=` and `..`
+/// are accepted, or as an inclusive `low, high` pair. A signed type is chosen
+/// when the low bound is negative; otherwise an unsigned type is chosen, unless
+/// the `signed` option is given.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_range;
+///
+/// type T1 = auto_sized_range!(0..=255);         // expands to u8
+/// type T2 = auto_sized_range!(-1..=127);        // expands to i8
+/// type T3 = auto_sized_range!(-5..=200);        // expands to i16
+/// type T4 = auto_sized_range!(-40, 125);        // expands to i8
+/// type T5 = auto_sized_range!(0, 40000);        // expands to u16
+/// type T6 = auto_sized_range!(0, 40000, signed); // expands to i32
+/// ```
+#[proc_macro]
+pub fn auto_sized_range(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RangeInput);
+    let (low, high) = match range_bounds(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_range!", err),
+    };
+
+    if input.signed {
+        pick_signed_width(signed_bits_needed(low).max(signed_bits_needed(high))).into()
+    } else {
+        pick_bounds_type(low, high).into()
+    }
+}
+
+/// Returns the smallest unsigned integer type with at least the given number of
+/// bits, for declaring a bit field of that width.
+///
+/// [`auto_sized_for_bits!`] and [`auto_sized_flags!`] are the same macro under
+/// other names. A width of `0` or above 128 is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bits;
+///
+/// type Field = auto_sized_bits!(13); // expands to u16
+/// type Word = auto_sized_bits!(64);  // expands to u64
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_bits!(0); // no bits to hold
+/// ```
+#[proc_macro]
+pub fn auto_sized_bits(input: TokenStream) -> TokenStream {
+    expand_bits("auto_sized_bits!", input)
+}
+
+/// Returns the largest value representable in the given number of bits,
+/// suffixed with the type [`auto_sized_bits!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bits_val;
+///
+/// let max = auto_sized_bits_val!(13);
+/// // expands to: 8191u16
+/// ```
+#[proc_macro]
+pub fn auto_sized_bits_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match bits_type(&expr).and_then(|(bits, ty)| max_value_literal(bits, &ty)) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_bits_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type with at least the given number of
+/// bits, for sizing by capacity rather than by a concrete value.
+///
+/// The same macro as [`auto_sized_bits!`], so `auto_sized_for_bits!(24)` expands
+/// to `u32`. A count of `0` or above 128 is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_for_bits;
+///
+/// type Limb = auto_sized_for_bits!(24); // expands to u32
+/// type Wide = auto_sized_for_bits!(65); // expands to u128
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_for_bits!(129); // needs 129 bits
+/// ```
+#[proc_macro]
+pub fn auto_sized_for_bits(input: TokenStream) -> TokenStream {
+    expand_bits("auto_sized_for_bits!", input)
+}
+
+/// Returns the smallest signed integer type with at least the given number of
+/// bits besides the sign bit.
+///
+/// `auto_sized_for_bits_signed!(8)` needs nine bits in total and expands to
+/// `i16`. A count of `0` or above 127 is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_for_bits_signed;
+///
+/// type Sample = auto_sized_for_bits_signed!(7);  // expands to i8
+/// type Delta = auto_sized_for_bits_signed!(24);  // expands to i32
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_for_bits_signed!(128); // needs 129 bits
+/// ```
+#[proc_macro]
+pub fn auto_sized_for_bits_signed(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match signed_bits_type(&expr) {
+        Ok(ty) => ty.into(),
+        Err(err) => spanned_error("auto_sized_for_bits_signed!", err),
+    }
+}
+
+/// Returns the bit width of the type [`auto_sized_unsigned!`] would choose for the
+/// given literal, as a `usize` literal.
+///
+/// Accepts the same input and options as [`auto_sized_unsigned!`], including
+/// `width_from_digits` and suffixes, except `native`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bit_width;
+///
+/// const WIDTH: usize = auto_sized_bit_width!(300);
+/// // expands to: 16usize
+/// ```
+#[proc_macro]
+pub fn auto_sized_bit_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, width) = match unsigned_literal_width(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_bit_width!", err),
+    };
+
+    let bits = Literal::usize_suffixed(width as usize);
+    quote! { #bits }.into()
+}
+
+/// Returns the smallest unsigned integer type with at least the given number of
+/// bytes, for fields specified in bytes.
+///
+/// The byte count is converted to bits and sized by the same table as
+/// [`auto_sized_bits!`], so a 3-byte field expands to `u32`. Pass `exact` to
+/// reject counts other than 1, 2, 4, 8, and 16 instead of rounding up. A count
+/// of `0` or above 16 is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytes;
+///
+/// type Len = auto_sized_bytes!(3);         // expands to u32
+/// type Id = auto_sized_bytes!(16);         // expands to u128
+/// type Word = auto_sized_bytes!(4, exact); // expands to u32
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_bytes!(3, exact); // would need padding
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as BytesInput);
+    match bytes_type(&input) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_bytes!", err),
+    }
+}
+
+/// Returns the largest value storable in the given number of bytes, suffixed
+/// with the type [`auto_sized_bytes!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytes_val;
+///
+/// let max = auto_sized_bytes_val!(3);
+/// // expands to: 16777215u32, i.e. 0xFF_FFFF
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytes_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as BytesInput);
+    match bytes_type(&input).and_then(|(bits, ty)| max_value_literal(bits, &ty)) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_bytes_val!", err),
+    }
+}
+
+/// Returns the byte width of the type [`auto_sized_unsigned!`] would choose for the
+/// given literal, as a `usize` literal.
+///
+/// Accepts the same input and options as [`auto_sized_unsigned!`] except
+/// `native`.
+///
+/// The expansion is a plain literal with no cast, so it can be used as an array
+/// length, a const generic argument, or inside a `const { }` block.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_byte_width;
+///
+/// const SIZE: usize = auto_sized_byte_width!(300);
+/// // expands to: 2usize
+///
+/// struct Buffer<const N: usize>([u8; N]);
+///
+/// let header = [0u8; auto_sized_byte_width!(300)];
+/// let buffer = Buffer::<{ auto_sized_byte_width!(70000) }>([0; 4]);
+/// // expands to: [0u8; 2usize] and Buffer::<{ 4usize }>
+/// ```
+#[proc_macro]
+pub fn auto_sized_byte_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, width) = match unsigned_literal_width(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_byte_width!", err),
+    };
+
+    let bytes = Literal::usize_suffixed(width as usize / 8);
+    quote! { #bytes }.into()
+}
+
+/// Returns the `core::num::NonZeroU*` counterpart of the type
+/// [`auto_sized_unsigned!`] would choose for the given literal.
+///
+/// The input accepts the same forms as [`auto_sized_unsigned!`]. A value of `0`
+/// is rejected, since no `NonZero` type can hold zero, and a negative value is
+/// pointed to [`auto_sized_nonzero_signed!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_nonzero;
+///
+/// type Id = auto_sized_nonzero!(300);
+/// // expands to: type Id = core::num::NonZeroU16;
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_nonzero!(0); // NonZero cannot hold zero
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_unsigned_type(&expr) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_nonzero!", err),
+    }
+}
+
+/// Returns the given literal as a value of the type [`auto_sized_nonzero!`] would
+/// choose.
+///
+/// The expansion calls the const `NonZero*::new(..).unwrap()` without `unsafe`,
+/// so it works in `const` and `static` initializers. Zero is rejected during
+/// expansion, so the `unwrap` never panics.
+///
+/// # Examples
+/// ```
+/// use autosized_num::{auto_sized_nonzero, auto_sized_nonzero_val};
+///
+/// let id = auto_sized_nonzero_val!(300);
+/// // expands to: core::num::NonZeroU16::new(300).unwrap()
+///
+/// const SENTINEL: auto_sized_nonzero!(300) = auto_sized_nonzero_val!(300);
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_unsigned_type(&expr) {
+        Ok((value, ty)) => {
+            let value = Literal::u128_unsuffixed(value);
+            quote! { #ty::new(#value).unwrap() }.into()
+        }
+        Err(err) => spanned_error("auto_sized_nonzero_val!", err),
+    }
+}
+
+/// Same as [`auto_sized_nonzero!`], named to pair with
+/// [`auto_sized_nonzero_signed!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_nonzero_unsigned;
+///
+/// type T = auto_sized_nonzero_unsigned!(300);
+/// // expands to: type T = core::num::NonZeroU16;
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero_unsigned(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_unsigned_type(&expr) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_nonzero_unsigned!", err),
+    }
+}
+
+/// Same as [`auto_sized_nonzero_val!`], named to pair with
+/// [`auto_sized_nonzero_signed_val!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_nonzero_unsigned_val;
+///
+/// let x = auto_sized_nonzero_unsigned_val!(300);
+/// // expands to: core::num::NonZeroU16::new(300).unwrap()
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero_unsigned_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_unsigned_type(&expr) {
+        Ok((value, ty)) => {
+            let value = Literal::u128_unsuffixed(value);
+            quote! { #ty::new(#value).unwrap() }.into()
+        }
+        Err(err) => spanned_error("auto_sized_nonzero_unsigned_val!", err),
+    }
+}
+
+/// Returns the `core::num::NonZeroI*` counterpart of the type
+/// [`auto_sized_signed!`] would choose for the given literal.
+///
+/// A value of `0` is rejected, since no `NonZero` type can hold zero.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_nonzero_signed;
+///
+/// type T = auto_sized_nonzero_signed!(-200);
+/// // expands to: type T = core::num::NonZeroI16;
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero_signed(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_signed_type(&expr) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_nonzero_signed!", err),
+    }
+}
+
+/// Returns the given literal as a value of the type [`auto_sized_nonzero_signed!`]
+/// would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_nonzero_signed_val;
+///
+/// let y = auto_sized_nonzero_signed_val!(-200);
+/// // expands to: core::num::NonZeroI16::new(-200).unwrap()
+/// ```
+#[proc_macro]
+pub fn auto_sized_nonzero_signed_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match nonzero_signed_type(&expr) {
+        Ok((value, ty)) => {
+            let value = Literal::i128_unsuffixed(value);
+            quote! { #ty::new(#value).unwrap() }.into()
+        }
+        Err(err) => spanned_error("auto_sized_nonzero_signed_val!", err),
+    }
+}
+
+/// Returns `core::num::Wrapping` of the type [`auto_sized_int!`] would choose for
+/// the given literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping;
+///
+/// type W1 = auto_sized_wrapping!(300);  // expands to ::core::num::Wrapping<u16>
+/// type W2 = auto_sized_wrapping!(-200); // expands to ::core::num::Wrapping<i16>
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping!", err),
+    };
+
+    quote! { ::core::num::Wrapping<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Wrapping`, suffixed with the
+/// type [`auto_sized_int!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping_val;
+///
+/// let w = auto_sized_wrapping_val!(300);
+/// // expands to: ::core::num::Wrapping(300u16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (value, _) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping_val!", err),
+    };
+    let value = source_literal(value, &expr);
+
+    quote! { ::core::num::Wrapping(#value) }.into()
+}
+
+/// Returns `core::num::Wrapping` of the type [`auto_sized_unsigned!`] would
+/// choose for the given literal.
+///
+/// Accepts the same options as [`auto_sized_unsigned!`] except `native`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping_unsigned;
+///
+/// type Seq = auto_sized_wrapping_unsigned!(40_000); // expands to ::core::num::Wrapping<u16>
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping_unsigned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, ty) = match unsigned_literal(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping_unsigned!", err),
+    };
+
+    quote! { ::core::num::Wrapping<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Wrapping`, suffixed with the
+/// type [`auto_sized_unsigned!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping_unsigned_val;
+///
+/// static START: core::num::Wrapping<u16> = auto_sized_wrapping_unsigned_val!(40_000);
+/// // expands to: ::core::num::Wrapping(40_000u16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping_unsigned_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let value = match unsigned_literal(&input)
+        .and_then(|(value, ty)| unsigned_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping_unsigned_val!", err),
+    };
+    let value = source_literal(value, &input.expr);
+
+    quote! { ::core::num::Wrapping(#value) }.into()
+}
+
+/// Returns `core::num::Wrapping` of the type [`auto_sized_signed!`] would choose
+/// for the given literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping_signed;
+///
+/// type Phase = auto_sized_wrapping_signed!(200); // expands to ::core::num::Wrapping<i16>
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping_signed(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match signed_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping_signed!", err),
+    };
+
+    quote! { ::core::num::Wrapping<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Wrapping`, suffixed with the
+/// type [`auto_sized_signed!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_wrapping_signed_val;
+///
+/// const PHASE: core::num::Wrapping<i16> = auto_sized_wrapping_signed_val!(200);
+/// // expands to: ::core::num::Wrapping(200i16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_wrapping_signed_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let value = match signed_type(&expr)
+        .and_then(|(value, ty)| signed_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_wrapping_signed_val!", err),
+    };
+    let value = source_literal(value, &expr);
+
+    quote! { ::core::num::Wrapping(#value) }.into()
+}
+
+/// Returns `core::num::Saturating` of the type [`auto_sized_int!`] would choose
+/// for the given literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating;
+///
+/// type S1 = auto_sized_saturating!(300);  // expands to ::core::num::Saturating<u16>
+/// type S2 = auto_sized_saturating!(-200); // expands to ::core::num::Saturating<i16>
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating!", err),
+    };
+
+    quote! { ::core::num::Saturating<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Saturating`, suffixed with
+/// the type [`auto_sized_int!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating_val;
+///
+/// let s = auto_sized_saturating_val!(300);
+/// // expands to: ::core::num::Saturating(300u16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (value, _) = match int_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating_val!", err),
+    };
+    let value = source_literal(value, &expr);
+
+    quote! { ::core::num::Saturating(#value) }.into()
+}
+
+/// Returns `core::num::Saturating` of the type [`auto_sized_unsigned!`] would
+/// choose for the given literal.
+///
+/// Accepts the same options as [`auto_sized_unsigned!`] except `native`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating_unsigned;
+///
+/// type Total = auto_sized_saturating_unsigned!(1_000_000); // expands to ::core::num::Saturating<u32>
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating_unsigned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let (_, ty) = match unsigned_literal(&input) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating_unsigned!", err),
+    };
+
+    quote! { ::core::num::Saturating<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Saturating`, suffixed with the
+/// type [`auto_sized_unsigned!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating_unsigned_val;
+///
+/// const START: core::num::Saturating<u32> = auto_sized_saturating_unsigned_val!(1_000_000);
+/// // expands to: ::core::num::Saturating(1_000_000u32)
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating_unsigned_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let value = match unsigned_literal(&input)
+        .and_then(|(value, ty)| unsigned_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating_unsigned_val!", err),
+    };
+    let value = source_literal(value, &input.expr);
+
+    quote! { ::core::num::Saturating(#value) }.into()
+}
+
+/// Returns `core::num::Saturating` of the type [`auto_sized_signed!`] would choose
+/// for the given literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating_signed;
+///
+/// type Level = auto_sized_saturating_signed!(200); // expands to ::core::num::Saturating<i16>
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating_signed(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let (_, ty) = match signed_type(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating_signed!", err),
+    };
+
+    quote! { ::core::num::Saturating<#ty> }.into()
+}
+
+/// Returns the given literal wrapped in `core::num::Saturating`, suffixed with the
+/// type [`auto_sized_signed!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_saturating_signed_val;
+///
+/// const LEVEL: core::num::Saturating<i16> = auto_sized_saturating_signed_val!(200);
+/// // expands to: ::core::num::Saturating(200i16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_saturating_signed_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    let value = match signed_type(&expr)
+        .and_then(|(value, ty)| signed_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_saturating_signed_val!", err),
+    };
+    let value = source_literal(value, &expr);
+
+    quote! { ::core::num::Saturating(#value) }.into()
+}
+
+/// Returns the `core::sync::atomic` type wrapping the type [`auto_sized_int!`]
+/// would choose for the given literal.
+///
+/// There is no stable `AtomicU128` or `AtomicI128`, so literals that need 128 bits
+/// are rejected.
+///
+/// An optional `min = 8`, `16`, `32`, or `64` raises the width to at least that
+/// many bits, keeping the signedness. Targets without atomics for the smaller
+/// widths can use it as a fallback behind `target_has_atomic`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_atomic;
+///
+/// type A1 = auto_sized_atomic!(300);          // expands to ::core::sync::atomic::AtomicU16
+/// type A2 = auto_sized_atomic!(-200);         // expands to ::core::sync::atomic::AtomicI16
+/// type A3 = auto_sized_atomic!(300, min = 32); // expands to ::core::sync::atomic::AtomicU32
+///
+/// #[cfg(target_has_atomic = "16")]
+/// type Counter = auto_sized_atomic!(50_000);
+/// #[cfg(not(target_has_atomic = "16"))]
+/// type Counter = auto_sized_atomic!(50_000, min = 32);
+/// ```
+/// ```compile_fail
+/// type A = autosized_num::auto_sized_atomic!(18446744073709551616); // needs u128
+/// ```
+#[proc_macro]
+pub fn auto_sized_atomic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AtomicInput);
+    match atomic_literal(&input, false) {
+        Ok((_, atomic)) => quote! { ::core::sync::atomic::#atomic }.into(),
+        Err(err) => spanned_error("auto_sized_atomic!", err),
+    }
+}
+
+/// Returns a new atomic holding the given literal, using the type
+/// [`auto_sized_atomic!`] would choose.
+///
+/// Accepts the same `min = ...` option, and the constructor is `const`, so the
+/// pair declares a static without repeating the width.
+///
+/// # Examples
+/// ```
+/// use autosized_num::{auto_sized_atomic, auto_sized_atomic_val};
+///
+/// static HIGH_WATER: auto_sized_atomic!(50_000) = auto_sized_atomic_val!(50_000);
+/// // expands to: ::core::sync::atomic::AtomicU16::new(50_000u16)
+/// static COUNTER: core::sync::atomic::AtomicU16 = auto_sized_atomic_val!(300);
+/// // expands to: ::core::sync::atomic::AtomicU16::new(300u16)
+/// static WIDE: core::sync::atomic::AtomicU32 = auto_sized_atomic_val!(300, min = 32);
+/// // expands to: ::core::sync::atomic::AtomicU32::new(300u32)
+/// ```
+#[proc_macro]
+pub fn auto_sized_atomic_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AtomicInput);
+    let (value, atomic) = match atomic_literal(&input, false) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_atomic_val!", err),
+    };
+    let value = source_literal(value, &input.expr);
+
+    quote! { ::core::sync::atomic::#atomic::new(#value) }.into()
+}
+
+/// Returns the `core::sync::atomic` type wrapping the type
+/// [`auto_sized_signed!`] would choose, so non-negative literals also get an
+/// `AtomicI*`.
+///
+/// Accepts the same `min = ...` option as [`auto_sized_atomic!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_atomic_signed;
+///
+/// type A1 = auto_sized_atomic_signed!(200);          // expands to ::core::sync::atomic::AtomicI16
+/// type A2 = auto_sized_atomic_signed!(-5, min = 32); // expands to ::core::sync::atomic::AtomicI32
+/// ```
+#[proc_macro]
+pub fn auto_sized_atomic_signed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AtomicInput);
+    match atomic_literal(&input, true) {
+        Ok((_, atomic)) => quote! { ::core::sync::atomic::#atomic }.into(),
+        Err(err) => spanned_error("auto_sized_atomic_signed!", err),
+    }
+}
+
+/// Returns a new atomic holding the given literal, using the type
+/// [`auto_sized_atomic_signed!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_atomic_signed_val;
+///
+/// static DELTA: core::sync::atomic::AtomicI16 = auto_sized_atomic_signed_val!(200);
+/// // expands to: ::core::sync::atomic::AtomicI16::new(200i16)
+/// ```
+#[proc_macro]
+pub fn auto_sized_atomic_signed_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as AtomicInput);
+    let (value, atomic) = match atomic_literal(&input, true) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_atomic_signed_val!", err),
+    };
+    let value = source_literal(value, &input.expr);
+
+    quote! { ::core::sync::atomic::#atomic::new(#value) }.into()
+}
+
+/// Returns the little-endian bytes of the given literal, using the type
+/// [`auto_sized_unsigned!`] would choose.
+///
+/// Accepts the same input and options as [`auto_sized_unsigned!`], including
+/// `width_from_digits` and suffixes, except `native`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_le_bytes;
+///
+/// let bytes: [u8; 2] = auto_sized_le_bytes!(300);
+/// // expands to: (300u16).to_le_bytes()
+/// assert_eq!(bytes, [0x2C, 0x01]);
+/// ```
+#[proc_macro]
+pub fn auto_sized_le_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let value = match unsigned_literal(&input)
+        .and_then(|(value, ty)| unsigned_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_le_bytes!", err),
+    };
+
+    quote! { (#value).to_le_bytes() }.into()
+}
+
+/// Returns the big-endian bytes of the given literal, using the type
+/// [`auto_sized_unsigned!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_be_bytes;
+///
+/// let bytes: [u8; 2] = auto_sized_be_bytes!(300);
+/// // expands to: (300u16).to_be_bytes()
+/// assert_eq!(bytes, [0x01, 0x2C]);
+/// ```
+#[proc_macro]
+pub fn auto_sized_be_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnsignedInput);
+    let value = match unsigned_literal(&input)
+        .and_then(|(value, ty)| unsigned_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_be_bytes!", err),
+    };
+
+    quote! { (#value).to_be_bytes() }.into()
+}
+
+/// Reinterprets an unsigned bit pattern as a two's-complement value of the given
+/// width, suffixed with the smallest signed type that can represent it.
+///
+/// The width must be `8`, `16`, `32`, `64`, or `128`, and the pattern must fit
+/// in that many bits. Pass `exact` to keep the value in the signed type of that
+/// width instead.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_from_twos_complement;
+///
+/// let reset = auto_from_twos_complement!(0xFFF0, 16);
+/// // expands to: -16i8
+/// let field = auto_from_twos_complement!(0xFFF0, 16, exact);
+/// // expands to: -16i16
+/// ```
+/// ```compile_fail
+/// let x = autosized_num::auto_from_twos_complement!(0x1_0000, 16); // needs 17 bits
+/// ```
+#[proc_macro]
+pub fn auto_from_twos_complement(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TwosComplementInput);
+    let value = match twos_complement_value(&input)
+        .and_then(|(value, ty)| signed_suffixed(value, &ty).map_err(call_site_error))
+    {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_from_twos_complement!", err),
+    };
+
+    let value = source_literal(value, &input.expr);
+    quote! { #value }.into()
+}
+
+/// Returns the signed type [`auto_from_twos_complement!`] would choose for the
+/// given bit pattern and width.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_from_twos_complement_type;
+///
+/// type T1 = auto_from_twos_complement_type!(0xFFF0, 16);        // expands to i8
+/// type T2 = auto_from_twos_complement_type!(0xFFF0, 16, exact); // expands to i16
+/// type T3 = auto_from_twos_complement_type!(0x7FFF, 16);        // expands to i16
+/// ```
+#[proc_macro]
+pub fn auto_from_twos_complement_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TwosComplementInput);
+    match twos_complement_value(&input) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_from_twos_complement_type!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can represent the bitwise OR
+/// of the given literals.
+///
+/// Overlapping bits do not widen the result, so this differs from summing.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_or;
+///
+/// type Flags = auto_sized_or!(0x01, 0x80, 0x4000);
+/// // expands to: type Flags = u16;
+/// ```
+#[proc_macro]
+pub fn auto_sized_or(input: TokenStream) -> TokenStream {
+    let lits = parse_macro_input!(input with Punctuated::<LitInt, Token![,]>::parse_terminated);
+    let value = match or_literals(&lits) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_sized_or!", err),
+    };
+
+    pick_unsigned_type(value).into()
+}
+
+/// Returns the bitwise OR of the given literals as a value, suffixed with the
+/// smallest unsigned integer type that can represent it.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_or_val;
+///
+/// let flags = auto_sized_or_val!(0x01, 0x80, 0x4000);
+/// // expands to: 16513u16
+/// ```
+#[proc_macro]
+pub fn auto_sized_or_val(input: TokenStream) -> TokenStream {
+    let lits = parse_macro_input!(input with Punctuated::<LitInt, Token![,]>::parse_terminated);
+    match or_literals(&lits).and_then(smallest_unsigned_literal) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_or_val!", err),
+    }
+}
+
+/// Expands to `usize` when the given literal fits in the minimum pointer width
+/// Rust guarantees (16 bits).
+///
+/// Pointer width is not known at macro expansion time, so values above
+/// `u16::MAX` are rejected unless `assume_64bit` is passed, which allows the
+/// full `u64` range.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_usize;
+///
+/// type T = auto_sized_usize!(300);
+/// // expands to: type T = usize;
+///
+/// type U = auto_sized_usize!(5_000_000_000, assume_64bit);
+/// // expands to: type U = usize;
+/// ```
+#[proc_macro]
+pub fn auto_sized_usize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PointerWidthInput);
+    if let Err(err) = pointer_width_literal(&input, false) {
+        return spanned_error("auto_sized_usize!", err);
+    }
+
+    primitive("usize").into()
+}
+
+/// Expands to `isize` when the given literal fits in the minimum pointer width
+/// Rust guarantees (16 bits).
+///
+/// Pointer width is not known at macro expansion time, so values outside
+/// `i16::MIN..=i16::MAX` are rejected unless `assume_64bit` is passed, which
+/// allows the full `i64` range.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_isize;
+///
+/// type T = auto_sized_isize!(-200);
+/// // expands to: type T = isize;
+/// ```
+#[proc_macro]
+pub fn auto_sized_isize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PointerWidthInput);
+    if let Err(err) = pointer_width_literal(&input, true) {
+        return spanned_error("auto_sized_isize!", err);
+    }
+
+    primitive("isize").into()
+}
+
+/// Returns the smallest unsigned integer type with at least the given number of
+/// bits, for packing that many boolean flags.
+///
+/// Unlike [`auto_sized_unsigned!`], the argument is a bit count rather than a
+/// value: `auto_sized_flags!(9)` needs 9 bits and expands to `u16`. The same
+/// macro as [`auto_sized_bits!`], so a count of `0` or above 128 is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_flags;
+///
+/// type F1 = auto_sized_flags!(5);  // expands to u8
+/// type F2 = auto_sized_flags!(40); // expands to u64
+/// ```
+#[proc_macro]
+pub fn auto_sized_flags(input: TokenStream) -> TokenStream {
+    expand_bits("auto_sized_flags!", input)
+}
+
+/// Returns the smallest unsigned integer type with room for the given number of
+/// hexadecimal digits, at four bits per digit.
+///
+/// Sizing matches [`auto_sized_flags!`] for the same number of bits, so
+/// `auto_sized_hex_digits!(2)` and `auto_sized_flags!(8)` both expand to `u8`.
+/// More than 32 digits would need more than 128 bits and is rejected.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_hex_digits;
+///
+/// type Id = auto_sized_hex_digits!(8);   // expands to u32
+/// type Code = auto_sized_hex_digits!(3); // expands to u16
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_hex_digits!(33); // needs 132 bits
+/// ```
+#[proc_macro]
+pub fn auto_sized_hex_digits(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match hex_digits_type(&expr) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_hex_digits!", err),
+    }
+}
+
+/// Returns the largest value with the given number of hexadecimal digits,
+/// suffixed with the type [`auto_sized_hex_digits!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_hex_digits_val;
+///
+/// let max_id = auto_sized_hex_digits_val!(8);
+/// // expands to: 4294967295u32, i.e. 0xFFFF_FFFF
+/// ```
+#[proc_macro]
+pub fn auto_sized_hex_digits_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match hex_digits_type(&expr).and_then(|(bits, ty)| max_value_literal(bits, &ty)) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_hex_digits_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can hold every decimal number
+/// with up to the given number of digits, i.e. `10^N - 1`.
+///
+/// With `signed`, the smallest signed type holding `±(10^N - 1)` is chosen
+/// instead, leaving room for a leading minus. Up to 38 digits are supported.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_digits;
+///
+/// type D1 = auto_sized_digits!(7);          // expands to u32
+/// type D2 = auto_sized_digits!(20);         // expands to u128
+/// type D3 = auto_sized_digits!(4, signed);  // expands to i16
+/// ```
+/// ```compile_fail
+/// type D = autosized_num::auto_sized_digits!(39); // exceeds u128
+/// ```
+#[proc_macro]
+pub fn auto_sized_digits(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DigitsInput);
+    match digits_type(&input) {
+        Ok(ty) => ty.into(),
+        Err(err) => spanned_error("auto_sized_digits!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can index the given items,
+/// i.e. hold every value in `0..count`.
+///
+/// Without commas, every top-level token tree counts as one item; with commas,
+/// the comma-separated items are counted instead, ignoring a trailing comma. A
+/// group such as `(a b)` counts as one item, and no items at all yield `u8`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_count;
+///
+/// type T1 = auto_sized_count!(alpha beta gamma); // expands to u8
+/// type T2 = auto_sized_count!(a, b, c, d);       // expands to u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_count(input: TokenStream) -> TokenStream {
+    let count = count_items(input.into());
+    pick_unsigned_type(count.saturating_sub(1)).into()
+}
+
+/// Returns the number of items counted by [`auto_sized_count!`], suffixed with
+/// the smallest unsigned type that can hold the count itself.
+///
+/// The count may need a wider type than the indices do: 256 items index with
+/// `u8`, but the count is `256u16`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_count_val;
+///
+/// let n = auto_sized_count_val!(a, (b c), d);
+/// // expands to: 3u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_count_val(input: TokenStream) -> TokenStream {
+    match smallest_unsigned_literal(count_items(input.into())) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_count_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can index every element of the
+/// given array expression, i.e. hold every value in `0..len`.
+///
+/// Only the outer elements are counted, and they need not be literals. The
+/// `[expr; N]` repeat form is accepted when `N` is an integer literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_index_type;
+///
+/// type I1 = auto_index_type!([10, 20, 30, 40]); // expands to u8
+/// type I2 = auto_index_type!([0u8; 256]);       // expands to u8
+/// type I3 = auto_index_type!([0u8; 257]);       // expands to u16
+/// ```
+#[proc_macro]
+pub fn auto_index_type(input: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(input as Expr);
+    let len = match array_len(&expr) {
+        Ok(v) => v,
+        Err(err) => return spanned_error("auto_index_type!", err),
+    };
+
+    pick_unsigned_type(len.saturating_sub(1)).into()
+}
+
+/// Returns the smallest unsigned integer type that can index a collection of the
+/// given length, i.e. hold every value in `0..len`.
+///
+/// A collection of `n` items has indices `0` through `n - 1`, so the type is sized
+/// for `n - 1` rather than `n`: a capacity of 256 indexes with `u8`, even though
+/// [`auto_sized_unsigned!`] picks `u16` for 256 itself. A length of `0` has no
+/// valid index and yields `u8`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_len;
+///
+/// type I1 = auto_sized_len!(256); // expands to u8
+/// type I2 = auto_sized_len!(257); // expands to u16
+/// type I3 = auto_sized_len!(0);   // expands to u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_len(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match fold_unsigned(&expr) {
+        Ok(len) => pick_unsigned_type(len.saturating_sub(1)).into(),
+        Err(err) => spanned_error("auto_sized_len!", err),
+    }
+}
+
+/// Returns the given length suffixed with the type [`auto_sized_len!`] would
+/// choose, so it compares against indices without an `as` cast.
+///
+/// Because the type is sized for the last index, a length that is a power of two
+/// past a type's range, such as `256`, does not fit its own index type and is
+/// rejected. Use [`auto_sized_unsigned_val!`] when the length itself must be stored.
+///
+/// # Examples
+/// ```
+/// use autosized_num::{auto_sized_len, auto_sized_len_val};
+///
+/// const CAPACITY: auto_sized_len!(200) = auto_sized_len_val!(200);
+/// // expands to: 200u8
+/// let slot: auto_sized_len!(200) = 17;
+/// assert!(slot < CAPACITY);
+/// ```
+/// ```compile_fail
+/// let n = autosized_num::auto_sized_len_val!(256); // indices fit u8, 256 does not
+/// ```
+#[proc_macro]
+pub fn auto_sized_len_val(input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match len_literal(&expr) {
+        Ok(value) => {
+            let value = source_literal(value, &expr);
+            quote! { #value }.into()
+        }
+        Err(err) => spanned_error("auto_sized_len_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can hold the code point of the
+/// given char literal.
+///
+/// This is the char-only counterpart of passing a char to
+/// [`auto_sized_unsigned!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_char;
+///
+/// type T1 = auto_sized_char!('A');  // expands to u8
+/// type T2 = auto_sized_char!('€');  // expands to u16
+/// type T3 = auto_sized_char!('😀'); // expands to u32
+/// ```
+#[proc_macro]
+pub fn auto_sized_char(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitChar);
+    pick_unsigned_type(u128::from(lit.value())).into()
+}
+
+/// Returns the code point of the given char literal, suffixed with the type
+/// [`auto_sized_char!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_char_val;
+///
+/// let a = auto_sized_char_val!('A');
+/// // expands to: 65u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_char_val(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitChar);
+    match smallest_unsigned_literal(u128::from(lit.value())) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_char_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can hold the byte length of
+/// the given string literal.
+///
+/// The length is measured after unescaping, so `"\u{1F600}"` counts as 4 bytes.
+/// Passing `exclusive` sizes the type for the valid indices `0..len` instead, so
+/// a 256-byte string yields `u8` rather than `u16`.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_strlen;
+///
+/// type Cursor = auto_sized_strlen!("hello world");
+/// // expands to: type Cursor = u8;
+///
+/// type Index = auto_sized_strlen!(r"C:\path", exclusive);
+/// // expands to: type Index = u8;
+/// ```
+#[proc_macro]
+pub fn auto_sized_strlen(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as StrLenInput);
+    let len = input.lit.value().len() as u128;
+    let len = if input.exclusive.is_some() {
+        len.saturating_sub(1)
+    } else {
+        len
+    };
+
+    pick_unsigned_type(len).into()
+}
+
+/// Returns the byte length of the given string literal, suffixed with the type
+/// [`auto_sized_strlen!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_strlen_val;
+///
+/// let len = auto_sized_strlen_val!("hello world");
+/// // expands to: 11u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_strlen_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as StrLenInput);
+    if let Some(flag) = &input.exclusive {
+        return spanned_error(
+            "auto_sized_strlen_val!",
+            syn::Error::new(
+                flag.span(),
+                "does not take `exclusive`, since the length itself may not fit the index type",
+            ),
+        );
+    }
+
+    match smallest_unsigned_literal(input.lit.value().len() as u128) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_strlen_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can hold the length of the
+/// given byte string literal.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytelen;
+///
+/// type Offset = auto_sized_bytelen!(b"\x7FELF");
+/// // expands to: type Offset = u8;
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytelen(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitByteStr);
+    pick_unsigned_type(lit.value().len() as u128).into()
+}
+
+/// Returns the length of the given byte string literal, suffixed with the type
+/// [`auto_sized_bytelen!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytelen_val;
+///
+/// let len = auto_sized_bytelen_val!(b"\x7FELF");
+/// // expands to: 4u8
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytelen_val(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitByteStr);
+    match smallest_unsigned_literal(lit.value().len() as u128) {
+        Ok(value) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_bytelen_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type that can hold the length of a file,
+/// read at macro-expansion time relative to `CARGO_MANIFEST_DIR`.
+///
+/// Pairs with `include_bytes!` for typing offsets into embedded data. The
+/// expansion includes the file inside the type, so Cargo rebuilds the calling
+/// crate when the file changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytelen_file;
+///
+/// // with tests/data/blob.bin 300 bytes long
+/// static BLOB: &[u8] = include_bytes!("../tests/data/blob.bin");
+/// type Offset = auto_sized_bytelen_file!("tests/data/blob.bin"); // expands to u16
+/// let last: Offset = 299;
+/// assert_eq!(BLOB.len(), usize::from(last) + 1);
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_bytelen_file!("does/not/exist.bin");
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytelen_file(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    match file_len(&path) {
+        Ok((len, full)) => included_type(pick_unsigned_type(len), &full).into(),
+        Err(err) => spanned_error("auto_sized_bytelen_file!", err),
+    }
+}
+
+/// Returns the length of a file, suffixed with the type
+/// [`auto_sized_bytelen_file!`] would choose.
+///
+/// The expansion includes the file with `include_bytes!`, so Cargo rebuilds the
+/// calling crate when the file changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_bytelen_file_val;
+///
+/// let len = auto_sized_bytelen_file_val!("tests/data/blob.bin");
+/// // expands to, for the 300-byte file: 300u16
+/// assert_eq!(len, 300u16);
+/// ```
+#[proc_macro]
+pub fn auto_sized_bytelen_file_val(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    let len = file_len(&path).and_then(|(len, full)| Ok((smallest_unsigned_literal(len)?, full)));
+    match len {
+        Ok((value, full)) => included_value(value, &full).into(),
+        Err(err) => spanned_error("auto_sized_bytelen_file_val!", err),
+    }
+}
+
+/// Returns the smallest integer type that can represent the value of an
+/// environment variable read at macro-expansion time.
+///
+/// The value may be decimal or `0x`/`0b` prefixed, with an optional leading `-`.
+/// An optional second argument selects `unsigned` (the default), `signed`, or
+/// `int` semantics, matching [`auto_sized_unsigned!`], [`auto_sized_signed!`],
+/// and [`auto_sized_int!`].
+///
+/// Cargo does not track environment variables read by proc macros, so a changed
+/// value only takes effect once the calling crate is rebuilt. The value form,
+/// [`auto_sized_env_val!`], is tracked.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_env;
+///
+/// // with AUTOSIZED_TEST_LIMIT=2000 in the build environment
+/// type Limit = auto_sized_env!("AUTOSIZED_TEST_LIMIT");         // expands to u16
+/// type Offset = auto_sized_env!("AUTOSIZED_TEST_LIMIT", signed); // expands to i16
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_env!("AUTOSIZED_NUM_UNSET_VARIABLE"); // not set
+/// ```
+#[proc_macro]
+pub fn auto_sized_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as EnvInput);
+    match env_literal(&input) {
+        Ok((ty, _)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_env!", err),
+    }
+}
+
+/// Returns the value of an environment variable read at macro-expansion time,
+/// suffixed with the type [`auto_sized_env!`] would choose.
+///
+/// The expansion reads the variable again with `env!`, so Cargo rebuilds the
+/// calling crate when it changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_env_val;
+///
+/// // with AUTOSIZED_TEST_LIMIT=2000 in the build environment
+/// let max = auto_sized_env_val!("AUTOSIZED_TEST_LIMIT");
+/// // expands to: {
+/// //     const _: &str = ::core::env!("AUTOSIZED_TEST_LIMIT");
+/// //     2000u16
+/// // }
+/// assert_eq!(max, 2000u16);
+/// ```
+#[proc_macro]
+pub fn auto_sized_env_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as EnvInput);
+    match env_literal(&input) {
+        Ok((_, value)) => tracked_env_value(value, &input.name).into(),
+        Err(err) => spanned_error("auto_sized_env_val!", err),
+    }
+}
+
+/// Returns the smallest integer type that can represent the value of an
+/// environment variable read at macro-expansion time, or of a fallback literal
+/// when the variable is not set.
+///
+/// The variable is parsed like [`auto_sized_env!`] values, and the fallback
+/// accepts the same expressions as [`auto_sized_int!`]. An optional third argument
+/// selects `unsigned` (the default), `signed`, or `int` semantics. The fallback is
+/// checked even when the variable is set, so a build without the variable cannot
+/// break unnoticed, and errors name the source that was used.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_option_env;
+///
+/// // with RING_CAPACITY unset
+/// type Capacity = auto_sized_option_env!("RING_CAPACITY", 1024); // expands to u16
+/// type Skew = auto_sized_option_env!("CLOCK_SKEW", -5, signed);  // expands to i8
+/// ```
+#[proc_macro]
+pub fn auto_sized_option_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as OptionEnvInput);
+    match option_env_literal(&input) {
+        Ok((ty, _, _)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_option_env!", err),
+    }
+}
+
+/// Returns the value [`auto_sized_option_env!`] sizes, suffixed with the type it
+/// would choose.
+///
+/// The expansion is a block that records where the value came from in a `SOURCE`
+/// constant, so `cargo expand` shows whether a build used the variable or the
+/// fallback.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_option_env_val;
+///
+/// // with RING_CAPACITY unset
+/// const CAPACITY: u16 = auto_sized_option_env_val!("RING_CAPACITY", 1024);
+/// // expands to: {
+/// //     const SOURCE: &str = "fallback `1024` (environment variable `RING_CAPACITY` is not set)";
+/// //     1024u16
+/// // }
+/// ```
+#[proc_macro]
+pub fn auto_sized_option_env_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as OptionEnvInput);
+    match option_env_literal(&input) {
+        Ok((_, value, source)) => quote! {
+            {
+                /// Where `auto_sized_option_env_val!` read this value from.
+                #[allow(dead_code)]
+                const SOURCE: &str = #source;
+                #value
+            }
+        }
+        .into(),
+        Err(err) => spanned_error("auto_sized_option_env_val!", err),
+    }
+}
+
+/// Returns the smallest unsigned integer type for a component of the calling
+/// crate's version, read from `CARGO_PKG_VERSION_MAJOR`, `_MINOR`, or `_PATCH`.
+///
+/// `packed, 8, 8, 16` instead packs major, minor, and patch into one integer,
+/// major in the high bits, with the given field widths. The packed type is sized
+/// from the total width rather than the value, so a header layout keeps its type
+/// across releases; a component too large for its field is rejected.
+///
+/// Only the numeric components are supported, so pre-release tags such as
+/// `-beta.1` cannot be sized.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_pkg_version;
+///
+/// type Major = auto_sized_pkg_version!(major);
+/// // expands to: u8 for version 0.1.1
+/// type Packed = auto_sized_pkg_version!(packed, 8, 8, 16);
+/// // expands to: u32
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_pkg_version!(pre); // not numeric
+/// ```
+#[proc_macro]
+pub fn auto_sized_pkg_version(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PkgVersionInput);
+    match pkg_version_literal(&input) {
+        Ok((ty, _)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_pkg_version!", err),
+    }
+}
+
+/// Returns the version component or packed version [`auto_sized_pkg_version!`]
+/// sizes, suffixed with the type it would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_pkg_version_val;
+///
+/// const VERSION: u32 = auto_sized_pkg_version_val!(packed, 8, 8, 16);
+/// // expands to: 65537u32 for version 0.1.1
+/// ```
+#[proc_macro]
+pub fn auto_sized_pkg_version_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PkgVersionInput);
+    match pkg_version_literal(&input) {
+        Ok((_, value)) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_pkg_version_val!", err),
+    }
+}
+
+/// Returns the smallest integer type that can represent the integer stored in a
+/// file, read at macro-expansion time relative to `CARGO_MANIFEST_DIR`.
+///
+/// The contents are trimmed and parsed like [`auto_sized_env!`] values: decimal
+/// or `0x`/`0b` prefixed, with an optional leading `-`. Types are chosen like
+/// [`auto_sized_int!`] unless a second argument of `unsigned` or `signed` is
+/// given.
+///
+/// The expansion includes the file inside the type, as the length of an array
+/// the type is projected out of, so Cargo rebuilds the calling crate when the
+/// file changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_file;
+///
+/// // with tests/data/max_packet.txt containing "1500"
+/// type Packet = auto_sized_from_file!("tests/data/max_packet.txt"); // expands to u16
+/// assert_eq!(Packet::MAX, u16::MAX);
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_from_file!("does/not/exist.txt");
+/// ```
+#[proc_macro]
+pub fn auto_sized_from_file(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as EnvInput);
+    match file_literal(&input) {
+        Ok((ty, _, path)) => included_type(ty, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_file!", err),
+    }
+}
+
+/// Returns the integer stored in a file, suffixed with the type
+/// [`auto_sized_from_file!`] would choose.
+///
+/// The expansion includes the file with `include_bytes!`, so Cargo rebuilds the
+/// calling crate when the file changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_file_val;
+///
+/// // with tests/data/max_packet.txt containing "1500"
+/// const MAX_PACKET: u16 = auto_sized_from_file_val!("tests/data/max_packet.txt");
+/// assert_eq!(MAX_PACKET, 1500);
+/// ```
+#[proc_macro]
+pub fn auto_sized_from_file_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as EnvInput);
+    match file_literal(&input) {
+        Ok((_, value, path)) => included_value(value, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_file_val!", err),
+    }
+}
+
+/// Returns the smallest integer type that can represent an integer read from a
+/// TOML file at macro-expansion time, relative to `CARGO_MANIFEST_DIR`.
+///
+/// The second argument is a dotted key such as `"network.max_payload"`, and the
+/// value it names must be an integer or a float holding an exact integer. Types
+/// are chosen like [`auto_sized_int!`] unless a third argument of `unsigned` or
+/// `signed` is given. Requires the `toml` feature.
+///
+/// As with [`auto_sized_from_file!`], the expansion includes the file, so Cargo
+/// rebuilds the calling crate when it changes.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_toml;
+///
+/// // with tests/data/limits.toml containing
+/// // [network]
+/// // max_payload = 1_500
+/// type Payload = auto_sized_from_toml!("tests/data/limits.toml", "network.max_payload"); // u16
+/// assert_eq!(Payload::MAX, u16::MAX);
+/// ```
+#[cfg(feature = "toml")]
+#[proc_macro]
+pub fn auto_sized_from_toml(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ConfigInput);
+    match config_literal(&input, toml_number) {
+        Ok((ty, _, path)) => included_type(ty, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_toml!", err),
+    }
+}
+
+/// Returns the integer read by [`auto_sized_from_toml!`], suffixed with the type
+/// it would choose.
+///
+/// The expansion includes the file with `include_bytes!`, so Cargo rebuilds the
+/// calling crate when the file changes. Requires the `toml` feature.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_toml_val;
+///
+/// const MAX_PAYLOAD: u16 = auto_sized_from_toml_val!("tests/data/limits.toml", "network.max_payload");
+/// assert_eq!(MAX_PAYLOAD, 1500);
+/// ```
+#[cfg(feature = "toml")]
+#[proc_macro]
+pub fn auto_sized_from_toml_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ConfigInput);
+    match config_literal(&input, toml_number) {
+        Ok((_, value, path)) => included_value(value, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_toml_val!", err),
+    }
+}
+
+/// Like [`auto_sized_from_toml!`], for a JSON file. Requires the `json` feature.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_json;
+///
+/// // with tests/data/limits.json containing {"network": {"max_payload": 1500, ...}}
+/// type Payload = auto_sized_from_json!("tests/data/limits.json", "network.max_payload"); // u16
+/// assert_eq!(Payload::MAX, u16::MAX);
+/// ```
+#[cfg(feature = "json")]
+#[proc_macro]
+pub fn auto_sized_from_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ConfigInput);
+    match config_literal(&input, json_number) {
+        Ok((ty, _, path)) => included_type(ty, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_json!", err),
+    }
+}
+
+/// Like [`auto_sized_from_toml_val!`], for a JSON file. Requires the `json` feature.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_from_json_val;
+///
+/// const MAX_PAYLOAD: u16 = auto_sized_from_json_val!("tests/data/limits.json", "network.max_payload");
+/// assert_eq!(MAX_PAYLOAD, 1500);
+/// ```
+#[cfg(feature = "json")]
+#[proc_macro]
+pub fn auto_sized_from_json_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ConfigInput);
+    match config_literal(&input, json_number) {
+        Ok((_, value, path)) => included_value(value, &path).into(),
+        Err(err) => spanned_error("auto_sized_from_json_val!", err),
+    }
+}
+
+/// Returns the smallest integer type that can represent a numeric string written
+/// in the given radix.
+///
+/// The radix may be anything from 2 to 36, with digits beyond 9 written as
+/// letters in either case. `_` separators are ignored, and a leading `-` selects
+/// a signed type like [`auto_sized_int!`].
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_radix;
+///
+/// type T1 = auto_sized_radix!("1F4", 16);  // expands to u16
+/// type T2 = auto_sized_radix!("777", 8);   // expands to u16
+/// type T3 = auto_sized_radix!("-1000_0000", 2); // expands to i8
+/// ```
+/// ```compile_fail
+/// type T = autosized_num::auto_sized_radix!("1G4", 16); // `G` is not a hex digit
+/// ```
+#[proc_macro]
+pub fn auto_sized_radix(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RadixInput);
+    match radix_literal(&input) {
+        Ok((ty, _)) => ty.into(),
+        Err(err) => spanned_error("auto_sized_radix!", err),
+    }
+}
+
+/// Returns the value of a numeric string written in the given radix, suffixed
+/// with the type [`auto_sized_radix!`] would choose.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_sized_radix_val;
+///
+/// let x = auto_sized_radix_val!("1F4", 16);
+/// // expands to: 500u16
+/// ```
+#[proc_macro]
+pub fn auto_sized_radix_val(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RadixInput);
+    match radix_literal(&input) {
+        Ok((_, value)) => quote! { #value }.into(),
+        Err(err) => spanned_error("auto_sized_radix_val!", err),
+    }
+}
+
+/// Rewrites every `const NAME: _ = <literal>;` in a `mod` or `impl` block to use
+/// the smallest integer type that can represent the literal.
+///
+/// Types are chosen the same way as [`auto_sized_int!`]. Consts with an explicit
+/// type are left untouched, and consts whose value is not an integer literal are
+/// skipped.
+///
+/// # Examples
+/// ```
+/// use autosized_num::auto_size_consts;
+///
+/// #[auto_size_consts]
+/// mod protocol {
+///     pub const VERSION: _ = 2;       // becomes u8
+///     pub const MAX_FRAME: _ = 65535; // becomes u16
+///     pub const OFFSET: _ = -300;     // becomes i16
+///     pub const TIMEOUT: u64 = 30;    // left untouched
+/// }
+///
+/// let _: u16 = protocol::MAX_FRAME;
+/// ```
+#[proc_macro_attribute]
+pub fn auto_size_consts(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return spanned_error(
+            "auto_size_consts",
+            syn::Error::new_spanned(attr, "does not take any arguments"),
+        );
+    }
+
+    let mut item = parse_macro_input!(item as Item);
+    let result = match &mut item {
+        Item::Mod(ItemMod {
+            content: Some((_, items)),
+            ..
+        }) => items.iter_mut().try_for_each(|item| match item {
+            Item::Const(ItemConst { ty, expr, .. }) => auto_size_const(ty, expr),
+            _ => Ok(()),
+        }),
+        Item::Impl(ItemImpl { items, .. }) => items.iter_mut().try_for_each(|item| match item {
+            ImplItem::Const(ImplItemConst { ty, expr, .. }) => auto_size_const(ty, expr),
+            _ => Ok(()),
+        }),
+        item => {
+            return spanned_error(
+                "auto_size_consts",
+                syn::Error::new_spanned(
+                    item,
+                    "can only be applied to an inline `mod` or an `impl` block",
+                ),
+            );
+        }
+    };
+    if let Err(err) = result {
+        return spanned_error("auto_size_consts", err);
+    }
+
+    quote! { #item }.into()
+}
+
+/// Derives a conversion from a fieldless enum into the smallest integer type that
+/// holds all of its discriminants.
+///
+/// Discriminants are evaluated like [`auto_sized_int!`] input, with implicit ones
+/// counting up from the previous variant. If every discriminant is non-negative an
+/// unsigned type is chosen; otherwise the smallest signed type covering the lowest
+/// and highest discriminant is used.
+///
+/// A derive cannot add attributes to the enum it is applied to, so the chosen
+/// type is exposed through a `From<Enum>` impl. If the enum carries an explicit
+/// integer `#[repr]`, it must match the chosen type, which keeps a hand-written
+/// repr in sync as variants are added.
+///
+/// # Examples
+/// ```
+/// use autosized_num::AutoRepr;
+///
+/// #[derive(AutoRepr)]
+/// #[repr(u16)]
+/// enum Status {
+///     Ok = 200,
+///     NotFound = 404,
+/// }
+///
+/// let code: u16 = Status::NotFound.into();
+/// assert_eq!(code, 404);
+/// ```
+/// ```compile_fail
+/// #[derive(autosized_num::AutoRepr)]
+/// #[repr(u8)] // 404 needs u16
+/// enum Status {
+///     Ok = 200,
+///     NotFound = 404,
+/// }
+/// ```
+#[proc_macro_derive(AutoRepr)]
+pub fn auto_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match auto_repr_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => spanned_error("AutoRepr", err),
+    }
+}
+
+fn auto_repr_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(call_site_error("can only be derived for enums"));
+    };
+    if data.variants.is_empty() {
+        return Err(call_site_error(
+            "cannot be derived for an enum without variants",
+        ));
+    }
+
+    let mut bounds: Option<(i128, i128)> = None;
+    let mut next = 0i128;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "can only be derived for fieldless enums",
+            ));
+        }
+        let value = match &variant.discriminant {
+            Some((_, expr)) => fold_signed(expr)?,
+            None => next,
+        };
+        next = value.checked_add(1).unwrap_or(value);
+        bounds = Some(match bounds {
+            Some((low, high)) => (low.min(value), high.max(value)),
+            None => (value, value),
+        });
+    }
+    let (low, high) = bounds.unwrap_or_default();
+    let repr = pick_bounds_type(low, high);
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let Some(ident) = meta.path.get_ident() else {
+                return Ok(());
+            };
+            let written = ident.to_string();
+            let is_int = written.starts_with(['u', 'i'])
+                && written[1..]
+                    .parse::<u32>()
+                    .is_ok_and(|bits| bits.is_power_of_two());
+            let fitted = primitive_name(&repr);
+            if is_int && written != fitted {
+                return Err(meta.error(format!(
+                    "discriminants fit in `{fitted}`, but the enum is `#[repr({written})]`"
+                )));
+            }
+            Ok(())
+        })?;
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #repr #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                value as #repr
+            }
+        }
+    })
+}
+
+/// Replaces a placeholder `_` const type with the type [`auto_sized_int!`] would
+/// choose for `expr`, leaving explicit types and non-integer values alone.
+fn auto_size_const(ty: &mut Type, expr: &Expr) -> syn::Result<()> {
+    if !matches!(ty, Type::Infer(_)) {
+        return Ok(());
+    }
+    match int_type(expr) {
+        Ok((_, sized)) => *ty = parse_quote!(#sized),
+        // Only surface errors for integer literals; anything else is not ours to size.
+        Err(err) if int_literal(expr).is_some() => return Err(err),
+        Err(_) => {}
+    }
+    Ok(())
+}
+
+/// Input of the unsigned macros: an integer, char, or byte literal (or a
+/// primitive `MAX` constant), optionally followed by a `=> u16` hint, then
+/// `, width_from_digits`, `, native`, `, min = u16`, and `, max = u64`.
+struct UnsignedInput {
+    expr: Expr,
+    hint: Option<Ident>,
+    width_from_digits: bool,
+    native: Option<Ident>,
+    min: Option<Ident>,
+    max: Option<Ident>,
+}
+
+impl Parse for UnsignedInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let hint = if input.parse::<Option<Token![=>]>>()?.is_some() {
+            let hint: Ident = input.parse()?;
+            if !["u8", "u16", "u32", "u64", "u128"]
+                .iter()
+                .any(|ty| hint == ty)
+            {
+                return Err(syn::Error::new(
+                    hint.span(),
+                    "unknown type hint, expected `u8`, `u16`, `u32`, `u64`, or `u128`",
+                ));
+            }
+            Some(hint)
+        } else {
+            None
+        };
+        let mut seen = Vec::new();
+        let mut width_from_digits = false;
+        let mut native = None;
+        let mut min = None;
+        let mut max = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            reject_duplicate(&mut seen, &flag)?;
+            if flag == "width_from_digits" {
+                width_from_digits = true;
+            } else if flag == "native" {
+                native = Some(flag);
+            } else if flag == "min" {
+                min = Some(parse_width_name(
+                    input,
+                    "minimum",
+                    &["u8", "u16", "u32", "u64", "u128"],
+                )?);
+            } else if flag == "max" {
+                max = Some(parse_width_name(
+                    input,
+                    "maximum",
+                    &["u8", "u16", "u32", "u64"],
+                )?);
+            } else {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `width_from_digits`, `native`, `min = ...`, or `max = ...`",
+                ));
+            }
+        }
+        check_width_bounds(&min, &max)?;
+        expect_end(input)?;
+        Ok(Self {
+            expr,
+            hint,
+            width_from_digits,
+            native,
+            min,
+            max,
+        })
+    }
+}
+
+/// Input of the integer macros: an expression, optionally preceded by a single
+/// `+` directly before an integer literal and followed by a trailing comma.
+struct IntExpr {
+    expr: Expr,
+    /// Whether the input was written with a leading `+`, which the int macros
+    /// take as a request for an unsigned type.
+    plus: bool,
+}
+
+impl Parse for IntExpr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let plus = input.peek(Token![+]);
+        let expr = parse_int_expr(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        expect_end(input)?;
+        Ok(Self { expr, plus })
+    }
+}
+
+/// Input of [`auto_sized_int!`] and [`auto_sized_int_val!`]: an [`IntExpr`],
+/// optionally followed by `, ptr_width = 16`, `32`, or `64`, `, min = u32`, and
+/// `, max = i64`.
+struct IntInput {
+    int: IntExpr,
+    ptr_width: Option<u32>,
+    min: Option<Ident>,
+    max: Option<Ident>,
+}
+
+impl Parse for IntInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let plus = input.peek(Token![+]);
+        let expr = parse_int_expr(input)?;
+        let mut seen = Vec::new();
+        let mut ptr_width = None;
+        let mut min = None;
+        let mut max = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            reject_duplicate(&mut seen, &flag)?;
+            if flag == "min" {
+                min = Some(parse_width_name(
+                    input,
+                    "minimum",
+                    &[
+                        "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+                    ],
+                )?);
+            } else if flag == "max" {
+                max = Some(parse_width_name(
+                    input,
+                    "maximum",
+                    &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"],
+                )?);
+            } else if flag == "ptr_width" {
+                input.parse::<Token![=]>()?;
+                let width: LitInt = input.parse()?;
+                match width.base10_digits() {
+                    "16" | "32" | "64" if width.suffix().is_empty() => {
+                        ptr_width = Some(width.base10_parse()?);
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            width.span(),
+                            "unknown pointer width, expected `16`, `32`, or `64`",
+                        ));
+                    }
+                }
+            } else {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `ptr_width = ...`, `min = ...`, or `max = ...`",
+                ));
+            }
+        }
+        check_width_bounds(&min, &max)?;
+        expect_end(input)?;
+        Ok(Self {
+            int: IntExpr { expr, plus },
+            ptr_width,
+            min,
+            max,
+        })
+    }
+}
+
+/// Parses an expression, dropping a leading `+` that Rust's grammar lacks. The
+/// `+` is only accepted directly before an integer literal, so `+-10` and `++10`
+/// are rejected.
+fn parse_int_expr(input: ParseStream) -> syn::Result<Expr> {
+    if input.is_empty() {
+        return Err(call_site_error("missing integer argument"));
+    }
+    if let Some(plus) = input.parse::<Option<Token![+]>>()?
+        && (input.peek(Token![-]) || !input.peek(LitInt))
+    {
+        return Err(syn::Error::new(
+            plus.span,
+            "only accepts a unary `+` directly before an integer literal",
+        ));
+    }
+    input.parse()
+}
+
+/// Rejects an option that already appeared in the same macro call, rather than
+/// letting the last one win.
+fn reject_duplicate(seen: &mut Vec<String>, flag: &Ident) -> syn::Result<()> {
+    let name = flag.to_string();
+    if seen.contains(&name) {
+        return Err(syn::Error::new(
+            flag.span(),
+            format!("duplicate option `{name}`"),
+        ));
+    }
+    seen.push(name);
+    Ok(())
+}
+
+/// Returns the bit width named by a `min` or `max` option, such as `u16`.
+fn option_width(ty: &Ident) -> u32 {
+    ty.to_string()[1..].parse().unwrap_or(u128::BITS)
+}
+
+/// Parses the `= u16` of a `min` or `max` option, which must name one of
+/// `allowed`.
+fn parse_width_name(input: ParseStream, bound: &str, allowed: &[&str]) -> syn::Result<Ident> {
+    input.parse::<Token![=]>()?;
+    let ty: Ident = input.parse()?;
+    if !allowed.iter().any(|name| ty == name) {
+        let (last, rest) = allowed.split_last().expect("width names");
+        let rest: Vec<_> = rest.iter().map(|name| format!("`{name}`")).collect();
+        return Err(syn::Error::new(
+            ty.span(),
+            format!(
+                "unknown {bound} width, expected {}, or `{last}`",
+                rest.join(", ")
+            ),
+        ));
+    }
+    Ok(ty)
+}
+
+/// Reports a value whose chosen type is wider than a `max` option allows.
+fn width_cap_error(expr: &Expr, value: impl Display, ty: &str, cap: &Ident) -> syn::Error {
+    syn::Error::new_spanned(
+        expr,
+        format!("value {value} needs `{ty}`, which exceeds the requested maximum width `{cap}`"),
+    )
+}
+
+/// Rejects a `min` option that names a wider type than the `max` option.
+fn check_width_bounds(min: &Option<Ident>, max: &Option<Ident>) -> syn::Result<()> {
+    if let (Some(floor), Some(cap)) = (min, max)
+        && option_width(floor) > option_width(cap)
+    {
+        return Err(syn::Error::new(
+            floor.span(),
+            format!("minimum width `{floor}` is wider than the maximum width `{cap}`"),
+        ));
+    }
+    Ok(())
+}
+
+/// Input of [`auto_sized_unsigned_in!`]: a bracketed list of unsigned types, a
+/// comma, and the integer argument.
+struct UnsignedInInput {
+    types: Vec<Ident>,
+    expr: Expr,
+}
+
+impl Parse for UnsignedInInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let list;
+        let brackets = syn::bracketed!(list in input);
+        let types: Punctuated<Ident, Token![,]> = list.parse_terminated(Ident::parse, Token![,])?;
+        if types.is_empty() {
+            return Err(syn::Error::new(
+                brackets.span.join(),
+                "expects at least one candidate type",
+            ));
+        }
+        for ty in &types {
+            if !["u8", "u16", "u32", "u64", "u128"]
+                .iter()
+                .any(|name| ty == name)
+            {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    "unknown candidate type, expected `u8`, `u16`, `u32`, `u64`, or `u128`",
+                ));
+            }
+        }
+        input.parse::<Token![,]>()?;
+        let expr = parse_int_expr(input)?;
+        input.parse::<Option<Token![,]>>()?;
+        expect_end(input)?;
+        Ok(Self {
+            types: types.into_iter().collect(),
+            expr,
+        })
+    }
+}
+
+/// Picks the narrowest candidate of [`auto_sized_unsigned_in!`] that is at least
+/// as wide as the type [`auto_sized_unsigned!`] would choose.
+fn unsigned_in_type(input: &UnsignedInInput) -> syn::Result<proc_macro2::TokenStream> {
+    let (value, width) = unsigned_expr_width(&input.expr, false)?;
+    input
+        .types
+        .iter()
+        .filter(|ty| option_width(ty) >= width)
+        .min_by_key(|ty| option_width(ty))
+        .map(|ty| primitive(ty.to_string()))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.expr,
+                format!("{value} needs `u{width}`, which is wider than every listed type"),
+            )
+        })
+}
+
+/// Input of [`auto_sized_const!`] and the type alias macros: an optional visibility
+/// and a name, then a comma and the integer argument.
+struct ConstInput {
+    vis: Visibility,
+    name: Ident,
+    expr: Expr,
+}
+
+impl Parse for ConstInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let IntExpr { expr, .. } = input.parse()?;
+        Ok(Self { vis, name, expr })
+    }
+}
+
+/// Input of [`auto_sized_newtype!`]: a [`ConstInput`], optionally followed by
+/// `, serde`.
+struct NewtypeInput {
+    vis: Visibility,
+    name: Ident,
+    expr: Expr,
+    serde: bool,
+}
+
+impl Parse for NewtypeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let expr = parse_int_expr(input)?;
+        let mut serde = false;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "serde" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `serde`",
+                ));
+            }
+            serde = true;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self {
+            vis,
+            name,
+            expr,
+            serde,
+        })
+    }
+}
+
+/// Input of [`auto_sized_float!`] and [`auto_sized_float_val!`]: a float
+/// literal, or an integer expression sized by its significant bits.
+enum FloatInput {
+    Float(LitFloat),
+    Int(Expr),
+}
+
+impl Parse for FloatInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitFloat) {
+            let lit = input.parse()?;
+            expect_end(input)?;
+            return Ok(Self::Float(lit));
+        }
+        let IntExpr { expr, .. } = input.parse()?;
+        Ok(Self::Int(expr))
+    }
+}
+
+/// Input of [`auto_sized_signed!`] and [`auto_sized_signed_val!`]: an integer
+/// expression, optionally followed by `, native` and `, max = i32`.
+struct SignedInput {
+    expr: Expr,
+    native: bool,
+    min: Option<Ident>,
+    max: Option<Ident>,
+}
+
+impl Parse for SignedInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let mut seen = Vec::new();
+        let mut native = false;
+        let mut min = None;
+        let mut max = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            reject_duplicate(&mut seen, &flag)?;
+            if flag == "native" {
+                native = true;
+            } else if flag == "min" {
+                min = Some(parse_width_name(
+                    input,
+                    "minimum",
+                    &["i8", "i16", "i32", "i64", "i128"],
+                )?);
+            } else if flag == "max" {
+                max = Some(parse_width_name(
+                    input,
+                    "maximum",
+                    &["i8", "i16", "i32", "i64"],
+                )?);
+            } else {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `native`, `min = ...`, or `max = ...`",
+                ));
+            }
+        }
+        check_width_bounds(&min, &max)?;
+        expect_end(input)?;
+        Ok(Self {
+            expr,
+            native,
+            min,
+            max,
+        })
+    }
+}
+
+/// Picks the type of a signed macro, widening it to the `min` option and
+/// checking it against the `max` option.
+fn capped_signed_type(input: &SignedInput) -> syn::Result<(i128, proc_macro2::TokenStream)> {
+    let (value, mut ty) = signed_type(&input.expr)?;
+    if let Some(min) = &input.min
+        && primitive_width(&ty) < option_width(min)
+    {
+        ty = primitive(min.to_string());
+    }
+    if let Some(max) = &input.max
+        && primitive_width(&ty) > option_width(max)
+    {
+        return Err(width_cap_error(
+            &input.expr,
+            value,
+            &primitive_name(&ty),
+            max,
+        ));
+    }
+    Ok((value, ty))
+}
+
+/// Input of [`auto_sized_struct!`]: a struct definition with named fields,
+/// where a field type may be an integer expression starting with a literal.
+struct StructInput {
+    attrs: Vec<syn::Attribute>,
+    vis: Visibility,
+    name: Ident,
+    generics: syn::Generics,
+    fields: Vec<(Vec<syn::Attribute>, Visibility, Ident, StructFieldType)>,
+}
+
+/// The type position of an [`auto_sized_struct!`] field.
+enum StructFieldType {
+    Int(Expr),
+    Type(Type),
+}
+
+impl Parse for StructInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        let mut generics: syn::Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        if !input.peek(syn::token::Brace) {
+            return Err(input.error("expects a struct with named fields in braces"));
+        }
+        let body;
+        syn::braced!(body in input);
+        let mut fields = Vec::new();
+        while !body.is_empty() {
+            let attrs = body.call(syn::Attribute::parse_outer)?;
+            let vis = body.parse()?;
+            let field = body.parse()?;
+            body.parse::<Token![:]>()?;
+            let ty = if body.peek(LitInt)
+                || ((body.peek(Token![-]) || body.peek(Token![+])) && body.peek2(LitInt))
+            {
+                StructFieldType::Int(parse_int_expr(&body)?)
+            } else if body.peek(LitFloat) {
+                return Err(body.error("expects an integer literal or a type"));
+            } else {
+                StructFieldType::Type(body.parse()?)
+            };
+            fields.push((attrs, vis, field, ty));
+            if body.parse::<Option<Token![,]>>()?.is_none() {
+                break;
+            }
+        }
+        expect_end(&body)?;
+        input.parse::<Option<Token![;]>>()?;
+        expect_end(input)?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            generics,
+            fields,
+        })
+    }
+}
+
+/// Input of [`auto_sized_cfg!`]: an optional `NAME,` with a visibility, then
+/// comma-separated `cfg(predicate) => literal` arms and an optional final
+/// `_ => literal` arm.
+struct CfgInput {
+    item: Option<(Visibility, Ident)>,
+    arms: Vec<(proc_macro2::TokenStream, Expr)>,
+    fallback: Option<Expr>,
+}
+
+impl Parse for CfgInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let item = if input.is_empty()
+            || input.peek(Token![_])
+            || (input.peek(syn::Ident) && input.peek2(syn::token::Paren))
+        {
+            None
+        } else {
+            let vis = input.parse()?;
+            let name = input.parse()?;
+            input.parse::<Token![,]>()?;
+            Some((vis, name))
+        };
+        let mut arms = Vec::new();
+        let mut fallback = None;
+        while !input.is_empty() {
+            if fallback.is_some() {
+                return Err(input.error("arms after `_` can never match"));
+            }
+            if input.parse::<Option<Token![_]>>()?.is_some() {
+                input.parse::<Token![=>]>()?;
+                fallback = Some(parse_int_expr(input)?);
+            } else {
+                let cfg: Ident = input.parse()?;
+                if cfg != "cfg" {
+                    return Err(syn::Error::new(cfg.span(), "expected `cfg(...)` or `_`"));
+                }
+                let predicate;
+                syn::parenthesized!(predicate in input);
+                let predicate = predicate.parse()?;
+                input.parse::<Token![=>]>()?;
+                arms.push((predicate, parse_int_expr(input)?));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        if arms.is_empty() && fallback.is_none() {
+            return Err(input.error("expects at least one `cfg(...) => literal` arm"));
+        }
+        Ok(Self {
+            item,
+            arms,
+            fallback,
+        })
+    }
+}
+
+/// Input of [`assert_fits!`]: an integer argument, a comma, and an integer type.
+struct FitsInput {
+    expr: Expr,
+    ty: Ident,
+}
+
+impl Parse for FitsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        input.parse::<Token![,]>()?;
+        let ty: Ident = input.parse()?;
+        let name = ty.to_string();
+        let known =
+            name.starts_with(['u', 'i']) && matches!(&name[1..], "8" | "16" | "32" | "64" | "128");
+        if !known {
+            return Err(syn::Error::new(
+                ty.span(),
+                "unknown type, expected one of `u8`..`u128` or `i8`..`i128`",
+            ));
+        }
+        input.parse::<Option<Token![,]>>()?;
+        expect_end(input)?;
+        Ok(Self { expr, ty })
+    }
+}
+
+/// Input of [`auto_sized_range!`]: a range expression or a `low, high` pair,
+/// optionally followed by `, signed`.
+struct RangeInput {
+    bounds: RangeBounds,
+    signed: bool,
+}
+
+/// The two ways [`auto_sized_range!`] accepts its bounds.
+enum RangeBounds {
+    Range(ExprRange),
+    Pair(Box<Expr>, Box<Expr>),
+}
+
+impl Parse for RangeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let bounds = match input.parse()? {
+            Expr::Range(range) => RangeBounds::Range(range),
+            low => {
+                input.parse::<Token![,]>()?;
+                RangeBounds::Pair(Box::new(low), input.parse()?)
+            }
+        };
+        let mut signed = false;
+        while input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "signed" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `signed`",
+                ));
+            }
+            signed = true;
+        }
+        expect_end(input)?;
+        Ok(Self { bounds, signed })
+    }
+}
+
+/// Checks that the argument of [`assert_fits!`] lies within its type's range.
+fn check_fits(input: &FitsInput) -> syn::Result<()> {
+    let name = input.ty.to_string();
+    let signed = name.starts_with('i');
+    let bits = option_width(&input.ty);
+    let (fits, shown) = match unsigned_expr_width(&input.expr, false) {
+        Ok((value, _)) if signed => (
+            i128::try_from(value).is_ok_and(|value| signed_bits_needed(value) <= bits),
+            value.to_string(),
+        ),
+        Ok((value, _)) => (unsigned_bits_needed(value) <= bits, value.to_string()),
+        Err(_) => {
+            let value = fold_signed(&input.expr)?;
+            let fits = if signed {
+                signed_bits_needed(value) <= bits
+            } else {
+                value >= 0 && unsigned_bits_needed(value as u128) <= bits
+            };
+            (fits, value.to_string())
+        }
+    };
+    if fits {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            &input.expr,
+            format!("{shown} does not fit in {name}"),
+        ))
+    }
+}
+
+/// Rejects tokens left over after the single argument of a macro.
+fn expect_end(input: ParseStream) -> syn::Result<()> {
+    if input.is_empty() {
+        return Ok(());
+    }
+    let stray: proc_macro2::TokenTree = input.fork().parse()?;
+    Err(input.error(format!(
+        "expected a single integer argument, found extra tokens starting at `{stray}`"
+    )))
+}
+
+/// Input of the string length macros: a string literal, optionally followed by
+/// `, exclusive`.
+struct StrLenInput {
+    lit: LitStr,
+    exclusive: Option<Ident>,
+}
+
+impl Parse for StrLenInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lit = input.parse()?;
+        let mut exclusive = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "exclusive" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `exclusive`",
+                ));
+            }
+            exclusive = Some(flag);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self { lit, exclusive })
+    }
+}
+
+/// Which integer family an environment-variable or file macro picks from.
+#[derive(Clone, Copy)]
+enum EnvMode {
+    Unsigned,
+    Signed,
+    Int,
+}
+
+/// Input of the environment-variable and file macros: a variable name or path,
+/// optionally followed by `, unsigned`, `, signed`, or `, int`.
+struct EnvInput {
+    name: LitStr,
+    mode: Option<EnvMode>,
+}
+
+impl Parse for EnvInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mode = parse_env_mode(input)?;
+        Ok(Self { name, mode })
+    }
+}
+
+/// Parses the optional `, unsigned`, `, signed`, or `, int` after the input of an
+/// environment-variable or file macro.
+fn parse_env_mode(input: ParseStream) -> syn::Result<Option<EnvMode>> {
+    if input.parse::<Option<Token![,]>>()?.is_none() || input.is_empty() {
+        return Ok(None);
+    }
+    let flag: Ident = input.parse()?;
+    let mode = match flag.to_string().as_str() {
+        "unsigned" => EnvMode::Unsigned,
+        "signed" => EnvMode::Signed,
+        "int" => EnvMode::Int,
+        _ => {
+            return Err(syn::Error::new(
+                flag.span(),
+                "unknown option, expected `unsigned`, `signed`, or `int`",
+            ));
+        }
+    };
+    input.parse::<Option<Token![,]>>()?;
+    Ok(Some(mode))
+}
+
+/// Input of the optional environment-variable macros: a variable name, a comma,
+/// a fallback literal, optionally followed by `, unsigned`, `, signed`, or `, int`.
+struct OptionEnvInput {
+    name: LitStr,
+    fallback: Expr,
+    mode: Option<EnvMode>,
+}
+
+impl Parse for OptionEnvInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let fallback = parse_int_expr(input)?;
+        let mode = parse_env_mode(input)?;
+        expect_end(input)?;
+        Ok(Self {
+            name,
+            fallback,
+            mode,
+        })
+    }
+}
+
+/// Input of the package version macros: `major`, `minor`, `patch`, or `packed`
+/// followed by three comma-separated field widths.
+enum PkgVersionInput {
+    Component(Ident),
+    Packed([LitInt; 3]),
+}
+
+impl Parse for PkgVersionInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let component: Ident = input.parse()?;
+        let parsed = match component.to_string().as_str() {
+            "major" | "minor" | "patch" => Self::Component(component),
+            "packed" => {
+                let width = || {
+                    input.parse::<Token![,]>()?;
+                    input.parse()
+                };
+                Self::Packed([width()?, width()?, width()?])
+            }
+            "pre" | "build" => {
+                return Err(syn::Error::new(
+                    component.span(),
+                    "only the numeric components `major`, `minor`, and `patch` are supported; \
+                     pre-release and build metadata are not numbers",
+                ));
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    component.span(),
+                    "unknown component, expected `major`, `minor`, `patch`, or `packed`",
+                ));
+            }
+        };
+        input.parse::<Option<Token![,]>>()?;
+        expect_end(input)?;
+        Ok(parsed)
+    }
+}
+
+/// Counts the top-level token trees of `tokens`, or its comma-separated items
+/// when it contains a top-level comma.
+fn count_items(tokens: proc_macro2::TokenStream) -> u128 {
+    let is_comma = |tt: &proc_macro2::TokenTree| matches!(tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == ',');
+    let trees: Vec<_> = tokens.into_iter().collect();
+    if !trees.iter().any(is_comma) {
+        return trees.len() as u128;
+    }
+    trees
+        .split(is_comma)
+        .filter(|item| !item.is_empty())
+        .count() as u128
+}
+
+/// Counts the outer elements of an array expression or `[expr; N]` repeat.
+fn array_len(expr: &Expr) -> syn::Result<u128> {
+    match strip_parens(expr) {
+        Expr::Array(array) => Ok(array.elems.len() as u128),
+        Expr::Repeat(repeat) => match strip_parens(&repeat.len) {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit
+                .base10_parse::<u128>()
+                .map_err(|_| received_error(lit, UNSIGNED_OVERFLOW)),
+            len => Err(received_error(
+                len,
+                "only accepts an integer literal as the repeat length",
+            )),
+        },
+        expr => Err(received_error(
+            expr,
+            "expects an array expression such as `[a, b, c]` or `[x; N]`",
+        )),
+    }
+}
+
+/// Returns the length in bytes of the file at `path`, relative to
+/// `CARGO_MANIFEST_DIR`, along with the resolved path.
+fn file_len(path: &LitStr) -> syn::Result<(u128, std::path::PathBuf)> {
+    let full = manifest_path(path);
+    std::fs::metadata(&full)
+        .map(|meta| (u128::from(meta.len()), full.clone()))
+        .map_err(|err| {
+            syn::Error::new(
+                path.span(),
+                format!("cannot read `{}`: {err}", full.display()),
+            )
+        })
+}
+
+/// Resolves `path` relative to `CARGO_MANIFEST_DIR`.
+fn manifest_path(path: &LitStr) -> std::path::PathBuf {
+    let relative = path.value();
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => std::path::Path::new(&dir).join(&relative),
+        None => std::path::PathBuf::from(&relative),
+    }
+}
+
+/// Reads and parses the file named by a file macro, returning the chosen type,
+/// the suffixed value, and the resolved path.
+fn file_literal(
+    input: &EnvInput,
+) -> syn::Result<(proc_macro2::TokenStream, Literal, std::path::PathBuf)> {
+    let full = manifest_path(&input.name);
+    let source = format!("file `{}`", full.display());
+    let text = std::fs::read_to_string(&full).map_err(|err| {
+        syn::Error::new(input.name.span(), format!("cannot read {source}: {err}"))
+    })?;
+    if text.trim().is_empty() {
+        return Err(syn::Error::new(
+            input.name.span(),
+            format!("{source} is empty"),
+        ));
+    }
+    let mode = input.mode.unwrap_or(EnvMode::Int);
+    let (ty, lit) = text_literal(&text, mode, &source, input.name.span())?;
+    Ok((ty, lit, full))
+}
+
+/// Wraps `value` in a block that includes the file at `path`, so Cargo rebuilds
+/// the calling crate when the file changes.
+fn included_value(value: Literal, path: &std::path::Path) -> proc_macro2::TokenStream {
+    let path = path.to_string_lossy();
+    quote! {
+        {
+            const _: &[u8] = ::core::include_bytes!(#path);
+            #value
+        }
+    }
+}
+
+/// Wraps `value` in a block that reads the variable `name` with `env!`, so Cargo
+/// rebuilds the calling crate when the variable changes.
+fn tracked_env_value(value: Literal, name: &LitStr) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            const _: &str = ::core::env!(#name);
+            #value
+        }
+    }
+}
+
+/// Wraps `ty` in a projection whose array length includes the file at `path`,
+/// so Cargo rebuilds the calling crate when the file changes. The projection
+/// normalizes back to `ty` wherever a type is accepted.
+fn included_type(ty: proc_macro2::TokenStream, path: &std::path::Path) -> proc_macro2::TokenStream {
+    let path = path.to_string_lossy();
+    quote! {
+        <[#ty; {
+            const _: &[u8] = ::core::include_bytes!(#path);
+            0
+        }] as ::core::iter::IntoIterator>::Item
+    }
+}
+
+/// Input of the config file macros: a path, a comma, a dotted key, optionally
+/// followed by `, unsigned`, `, signed`, or `, int`.
+#[cfg(any(feature = "toml", feature = "json"))]
+struct ConfigInput {
+    path: LitStr,
+    key: LitStr,
+    mode: Option<EnvMode>,
+}
+
+#[cfg(any(feature = "toml", feature = "json"))]
+impl Parse for ConfigInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let key = input.parse()?;
+        let mode = parse_env_mode(input)?;
+        expect_end(input)?;
+        Ok(Self { path, key, mode })
+    }
+}
+
+/// Finds a dotted key in the contents of a config file, returning its value as a
+/// sign and a magnitude, or a description of what went wrong.
+#[cfg(any(feature = "toml", feature = "json"))]
+type ConfigLookup = fn(&str, &str) -> Result<(bool, u128), String>;
+
+/// Reads the file of a config macro and looks up its key with `lookup`,
+/// returning the chosen type, the suffixed value, and the resolved path.
+#[cfg(any(feature = "toml", feature = "json"))]
+fn config_literal(
+    input: &ConfigInput,
+    lookup: ConfigLookup,
+) -> syn::Result<(proc_macro2::TokenStream, Literal, std::path::PathBuf)> {
+    let full = manifest_path(&input.path);
+    let key = input.key.value();
+    let error = |msg: &str| {
+        syn::Error::new(
+            input.key.span(),
+            format!("cannot read `{key}` from `{}`: {msg}", full.display()),
+        )
+    };
+    let text = std::fs::read_to_string(&full).map_err(|err| {
+        syn::Error::new(
+            input.path.span(),
+            format!("cannot read `{}` for key `{key}`: {err}", full.display()),
+        )
+    })?;
+    let (negative, magnitude) = lookup(&text, &key).map_err(|msg| error(&msg))?;
+    let mode = input.mode.unwrap_or(EnvMode::Int);
+    let (ty, lit) = sign_magnitude_literal(negative, magnitude, mode)
+        .map_err(|msg| error(&format!("the value {msg}")))?;
+    Ok((ty, lit, full))
+}
+
+/// Looks up a dotted key in TOML text.
+#[cfg(feature = "toml")]
+fn toml_number(text: &str, key: &str) -> Result<(bool, u128), String> {
+    let table: toml::Table = text
+        .parse()
+        .map_err(|err| format!("not valid TOML: {err}"))?;
+    let mut value = toml::Value::Table(table);
+    for segment in key.split('.') {
+        value = match value {
+            toml::Value::Table(mut table) => table.remove(segment),
+            _ => None,
+        }
+        .ok_or("no such key")?;
+    }
+    match value {
+        toml::Value::Integer(value) => Ok((value < 0, value.unsigned_abs().into())),
+        toml::Value::Float(value) => exact_f64(value),
+        value => Err(format!("expected an integer, found a {}", value.type_str())),
+    }
+}
+
+/// Looks up a dotted key in JSON text.
+#[cfg(feature = "json")]
+fn json_number(text: &str, key: &str) -> Result<(bool, u128), String> {
+    let mut value: serde_json::Value =
+        serde_json::from_str(text).map_err(|err| format!("not valid JSON: {err}"))?;
+    for segment in key.split('.') {
+        value = match value {
+            serde_json::Value::Object(mut object) => object.remove(segment),
+            _ => None,
+        }
+        .ok_or("no such key")?;
+    }
+    let serde_json::Value::Number(number) = value else {
+        return Err(format!("expected an integer, found `{value}`"));
+    };
+    if let Some(value) = number.as_u64() {
+        Ok((false, value.into()))
+    } else if let Some(value) = number.as_i64() {
+        Ok((value < 0, value.unsigned_abs().into()))
+    } else {
+        exact_f64(number.as_f64().unwrap_or(f64::NAN))
+    }
+}
+
+/// Splits a float holding an exact integer into a sign and a magnitude.
+#[cfg(any(feature = "toml", feature = "json"))]
+fn exact_f64(value: f64) -> Result<(bool, u128), String> {
+    if value.fract() != 0.0 || value.abs() >= 2f64.powi(128) {
+        return Err(format!("float {value} is not an exact integer"));
+    }
+    Ok((value < 0.0, value.abs() as u128))
+}
+
+/// Reads and parses the variable named by an environment-variable macro,
+/// returning the chosen type and the suffixed value.
+fn env_literal(input: &EnvInput) -> syn::Result<(proc_macro2::TokenStream, Literal)> {
+    let name = input.name.value();
+    let text = std::env::var(&name).map_err(|_| {
+        syn::Error::new(
+            input.name.span(),
+            format!("environment variable `{name}` is not set"),
+        )
+    })?;
+    let mode = input.mode.unwrap_or(EnvMode::Unsigned);
+    let source = format!("environment variable `{name}`");
+    text_literal(&text, mode, &source, input.name.span())
+}
+
+/// Reads the variable named by an optional environment-variable macro, falling
+/// back to its literal when unset, and returns the chosen type, the suffixed
+/// value, and a description of the source used.
+fn option_env_literal(
+    input: &OptionEnvInput,
+) -> syn::Result<(proc_macro2::TokenStream, Literal, String)> {
+    let name = input.name.value();
+    let mode = input.mode.unwrap_or(EnvMode::Unsigned);
+    let expr = &input.fallback;
+    let text = std::env::var(&name).ok();
+    let (negative, magnitude) = match fold_unsigned(expr) {
+        Ok(magnitude) => (false, magnitude),
+        Err(_) => {
+            let value = fold_signed(expr)?;
+            (value < 0, value.unsigned_abs())
+        }
+    };
+    let fallback = sign_magnitude_literal(negative, magnitude, mode).map_err(|msg| {
+        let used = match text {
+            Some(_) => format!("environment variable `{name}` is set and was used"),
+            None => format!("environment variable `{name}` is not set"),
+        };
+        syn::Error::new_spanned(expr, format!("fallback {msg} ({used})"))
+    })?;
+    let Some(text) = text else {
+        let source = format!(
+            "fallback `{}` (environment variable `{name}` is not set)",
+            expr.to_token_stream()
+        );
+        return Ok((fallback.0, source_literal(fallback.1, expr), source));
+    };
+    let source = format!("environment variable `{name}`");
+    let (ty, value) = text_literal(&text, mode, &source, input.name.span()).map_err(|err| {
+        syn::Error::new(
+            input.name.span(),
+            format!("{err} (it is set, so the fallback is not used)"),
+        )
+    })?;
+    Ok((ty, value, source))
+}
+
+/// Reads the requested version component, or packs all three, and returns the
+/// chosen type and the suffixed value.
+fn pkg_version_literal(
+    input: &PkgVersionInput,
+) -> syn::Result<(proc_macro2::TokenStream, Literal)> {
+    let (value, ty) = match input {
+        PkgVersionInput::Component(component) => {
+            let value = pkg_version_component(component)?;
+            (value, pick_unsigned_type(value))
+        }
+        PkgVersionInput::Packed(widths) => {
+            let mut total = 0;
+            for width in widths {
+                total += width
+                    .base10_parse::<u32>()
+                    .ok()
+                    .filter(|bits| (1..=128).contains(bits))
+                    .ok_or_else(|| received_error(width, "expects a field width from 1 to 128"))?;
+            }
+            let ty = pick_unsigned_width(total).ok_or_else(|| {
+                call_site_error(&format!(
+                    "field widths add up to {total} bits, but the widest type `u128` has 128"
+                ))
+            })?;
+            let mut packed = 0u128;
+            for (name, width) in ["major", "minor", "patch"].into_iter().zip(widths) {
+                let bits: u32 = width.base10_parse()?;
+                let value = pkg_version_component(&Ident::new(name, width.span()))?;
+                if value.checked_shr(bits).unwrap_or(0) != 0 {
+                    return Err(syn::Error::new(
+                        width.span(),
+                        format!("{name} version {value} does not fit in {bits} bits"),
+                    ));
+                }
+                // A 128-bit field is the only one, so nothing is shifted out.
+                packed = packed.checked_shl(bits).unwrap_or(0) | value;
+            }
+            (packed, ty)
+        }
+    };
+    let value = unsigned_suffixed(value, &ty).map_err(call_site_error)?;
+    Ok((ty, value))
+}
+
+/// Reads `CARGO_PKG_VERSION_<COMPONENT>`, which Cargo sets for the crate being
+/// compiled.
+fn pkg_version_component(component: &Ident) -> syn::Result<u128> {
+    let name = format!("CARGO_PKG_VERSION_{}", component.to_string().to_uppercase());
+    let text = std::env::var(&name).map_err(|_| {
+        syn::Error::new(
+            component.span(),
+            format!("environment variable `{name}` is not set; build with Cargo"),
+        )
+    })?;
+    text.parse().map_err(|_| {
+        syn::Error::new(
+            component.span(),
+            format!("`{name}` is `{text}`, but only numeric components are supported"),
+        )
+    })
+}
+
+/// Parses the integer text read from `source` and picks its type by `mode`,
+/// returning the type and the suffixed value.
+fn text_literal(
+    text: &str,
+    mode: EnvMode,
+    source: &str,
+    span: Span,
+) -> syn::Result<(proc_macro2::TokenStream, Literal)> {
+    let error = |msg: &str| syn::Error::new(span, format!("{source} {msg}: `{text}`"));
+    let text = text.trim();
+    let (negative, magnitude) = parse_env_int(text).ok_or_else(|| error("is not an integer"))?;
+    sign_magnitude_literal(negative, magnitude, mode).map_err(error)
+}
+
+/// Picks the type of a value given as a sign and a magnitude by `mode`,
+/// returning the type and the suffixed value.
+fn sign_magnitude_literal(
+    negative: bool,
+    magnitude: u128,
+    mode: EnvMode,
+) -> Result<(proc_macro2::TokenStream, Literal), &'static str> {
+    let signed = |magnitude: u128| {
+        let value = if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        let value = value.ok_or("does not fit in i128")?;
+        let ty = pick_signed_type(value);
+        let lit = signed_suffixed(value, &ty)?;
+        Ok((ty, lit))
+    };
+    match mode {
+        EnvMode::Signed => signed(magnitude),
+        EnvMode::Int if negative => signed(magnitude),
+        EnvMode::Unsigned if negative && magnitude != 0 => Err("is negative"),
+        EnvMode::Unsigned | EnvMode::Int => {
+            let ty = pick_unsigned_type(magnitude);
+            let lit = unsigned_suffixed(magnitude, &ty)?;
+            Ok((ty, lit))
+        }
+    }
+}
+
+/// Parses a decimal or `0x`/`0b` prefixed integer with an optional leading `-`,
+/// returning the sign and the magnitude.
+fn parse_env_int(text: &str) -> Option<(bool, u128)> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let magnitude = if let Some(hex) = digits.strip_prefix("0x") {
+        u128::from_str_radix(hex, 16).ok()?
+    } else if let Some(bin) = digits.strip_prefix("0b") {
+        u128::from_str_radix(bin, 2).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+    Some((negative, magnitude))
+}
+
+/// Input of the radix macros: a string literal, a comma, and the radix.
+struct RadixInput {
+    text: LitStr,
+    radix: LitInt,
+}
+
+impl Parse for RadixInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let text = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let radix = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        expect_end(input)?;
+        Ok(Self { text, radix })
+    }
+}
+
+/// Parses the string of a radix macro, returning the type and the suffixed value.
+fn radix_literal(input: &RadixInput) -> syn::Result<(proc_macro2::TokenStream, Literal)> {
+    let radix = input
+        .radix
+        .base10_parse::<u32>()
+        .ok()
+        .filter(|radix| (2..=36).contains(radix))
+        .ok_or_else(|| received_error(&input.radix, "expects a radix from 2 to 36"))?;
+    let text = input.text.value();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let mut magnitude = None::<u128>;
+    for c in digits.chars().filter(|&c| c != '_') {
+        let digit = c.to_digit(radix).ok_or_else(|| {
+            syn::Error::new(
+                input.text.span(),
+                format!("found `{c}`, which is not a base-{radix} digit"),
+            )
+        })?;
+        magnitude = Some(
+            magnitude
+                .unwrap_or(0)
+                .checked_mul(radix.into())
+                .and_then(|value| value.checked_add(digit.into()))
+                .ok_or_else(|| received_error(&input.text, UNSIGNED_OVERFLOW))?,
+        );
+    }
+    let magnitude =
+        magnitude.ok_or_else(|| received_error(&input.text, "expects at least one digit"))?;
+    sign_magnitude_literal(negative, magnitude, EnvMode::Int)
+        .map_err(|msg| received_error(&input.text, msg))
+}
+
+/// Input of the two's-complement macros: a bit pattern, a comma, and its width,
+/// optionally followed by `, exact`.
+struct TwosComplementInput {
+    expr: Expr,
+    width: u32,
+    exact: bool,
+}
+
+impl Parse for TwosComplementInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        input.parse::<Token![,]>()?;
+        let width: LitInt = input.parse()?;
+        let width = match width.base10_digits() {
+            "8" | "16" | "32" | "64" | "128" if width.suffix().is_empty() => {
+                width.base10_parse()?
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    width.span(),
+                    "unknown width, expected `8`, `16`, `32`, `64`, or `128`",
+                ));
+            }
+        };
+        let mut exact = false;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "exact" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `exact`",
+                ));
+            }
+            exact = true;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self { expr, width, exact })
+    }
+}
+
+/// Sign-extends the bit pattern of a two's-complement macro from its width,
+/// returning the value and the signed type chosen for it.
+fn twos_complement_value(
+    input: &TwosComplementInput,
+) -> syn::Result<(i128, proc_macro2::TokenStream)> {
+    let pattern = fold_unsigned(&input.expr)?;
+    let width = input.width;
+    if unsigned_bits_needed(pattern) > width {
+        return Err(received_error(
+            &input.expr,
+            &format!("does not fit in {width} bits"),
+        ));
+    }
+    let unused = u128::BITS - width;
+    let value = ((pattern << unused) as i128) >> unused;
+    let ty = if input.exact {
+        pick_signed_width(width)
+    } else {
+        pick_signed_type(value)
+    };
+    Ok((value, ty))
+}
+
+/// Input of [`auto_sized_digits!`]: a digit count, optionally followed by
+/// `, signed`.
+struct DigitsInput {
+    expr: Expr,
+    signed: bool,
+}
+
+impl Parse for DigitsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let mut signed = false;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "signed" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `signed`",
+                ));
+            }
+            signed = true;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self { expr, signed })
+    }
+}
+
+/// Picks the type [`auto_sized_digits!`] chooses for a digit count.
+fn digits_type(input: &DigitsInput) -> syn::Result<proc_macro2::TokenStream> {
+    let digits = fold_unsigned(&input.expr)?;
+    if digits == 0 {
+        return Err(received_error(&input.expr, "expects at least one digit"));
+    }
+    let largest = u32::try_from(digits)
+        .ok()
+        .and_then(|digits| 10u128.checked_pow(digits))
+        .map(|power| power - 1)
+        .ok_or_else(|| received_error(&input.expr, "supports at most 38 digits"))?;
+    Ok(if input.signed {
+        pick_signed_type(largest as i128)
+    } else {
+        pick_unsigned_type(largest)
+    })
+}
+
+/// Input of the byte-count macros: a byte count, optionally followed by
+/// `, exact`.
+struct BytesInput {
+    expr: Expr,
+    exact: bool,
+}
+
+impl Parse for BytesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let mut exact = false;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "exact" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `exact`",
+                ));
+            }
+            exact = true;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self { expr, exact })
+    }
+}
+
+/// Input of the pointer-width macros: an integer expression, optionally
+/// followed by `, assume_64bit`.
+struct PointerWidthInput {
+    expr: Expr,
+    assume_64bit: bool,
+}
+
+impl Parse for PointerWidthInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let mut assume_64bit = false;
+        if input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "assume_64bit" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `assume_64bit`",
+                ));
+            }
+            assume_64bit = true;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        expect_end(input)?;
+        Ok(Self { expr, assume_64bit })
+    }
+}
+
+/// Input of the atomic macros: an integer expression, optionally followed by
+/// `, min = 8`, `16`, `32`, or `64`.
+struct AtomicInput {
+    expr: Expr,
+    min: Option<u32>,
+}
+
+impl Parse for AtomicInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = parse_int_expr(input)?;
+        let mut min = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && input.peek(syn::Ident) {
+            let flag: Ident = input.parse()?;
+            if flag != "min" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    "unknown option, expected `min = ...`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let width: LitInt = input.parse()?;
+            match width.base10_digits() {
+                "8" | "16" | "32" | "64" if width.suffix().is_empty() => {
+                    min = Some(width.base10_parse()?);
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        width.span(),
+                        "unknown minimum width, expected `8`, `16`, `32`, or `64`; there are no 128-bit atomics",
+                    ));
+                }
+            }
+        }
+        expect_end(input)?;
+        Ok(Self { expr, min })
+    }
+}
+
+/// Picks the atomic type for the input of an atomic macro, widened to its
+/// `min` option, and returns the value suffixed with the matching integer type.
+fn atomic_literal(input: &AtomicInput, signed: bool) -> syn::Result<(Literal, Ident)> {
+    let (value, ty) = if signed {
+        let (value, ty) = signed_type(&input.expr)?;
+        let value = signed_suffixed(value, &ty).map_err(call_site_error)?;
+        (value, ty)
+    } else {
+        int_type(&input.expr)?
+    };
+    let atomic = atomic_type(&ty)?;
+    let name = primitive_name(&ty);
+    let bits = primitive_width(&ty);
+    let Some(floor) = input.min.filter(|&floor| floor > bits) else {
+        return Ok((value, atomic));
+    };
+    let widened = format!("{}{floor}", &name[..1]);
+    let digits = value.to_string();
+    let digits = &digits[..digits.len() - name.len()];
+    let value = format!("{digits}{widened}")
+        .parse()
+        .expect("re-suffixed integer literal");
+    Ok((value, atomic_type(&primitive(widened))?))
+}
+
+/// Checks that the literal of a pointer-width macro fits in 16 bits, or in
+/// 64 bits with `assume_64bit`.
+fn pointer_width_literal(input: &PointerWidthInput, signed: bool) -> syn::Result<()> {
+    let error = |msg| syn::Error::new_spanned(&input.expr, msg);
+    let value = signed_literal(&input.expr).map_err(|msg| received_error(&input.expr, msg))?;
+    let (min, max) = match (signed, input.assume_64bit) {
+        (false, false) => (0, u16::MAX as i128),
+        (false, true) => (0, u64::MAX as i128),
+        (true, false) => (i16::MIN as i128, i16::MAX as i128),
+        (true, true) => (i64::MIN as i128, i64::MAX as i128),
+    };
+    if !signed && value < 0 {
+        return Err(error("does not accept negative literals"));
+    }
+    if value < min || value > max {
+        return Err(error(if input.assume_64bit {
+            "value does not fit in a 64-bit pointer width"
+        } else {
+            "value does not fit in the guaranteed 16-bit pointer width; pass `assume_64bit` to allow larger values"
+        }));
+    }
+    Ok(())
+}
+
+/// Parses the literal of an unsigned macro and picks its type, either from the
+/// value or, with `width_from_digits`, from the number of digits written.
+/// An unsigned suffix on the literal acts as a minimum width.
+fn unsigned_literal(input: &UnsignedInput) -> syn::Result<(u128, proc_macro2::TokenStream)> {
+    let (value, width) = unsigned_literal_width(input)?;
+    Ok((value, primitive(format!("u{width}"))))
+}
+
+/// Like [`unsigned_literal`], but returns the bit width of the chosen type.
+fn unsigned_literal_width(input: &UnsignedInput) -> syn::Result<(u128, u32)> {
+    if let Some(native) = &input.native {
+        return Err(syn::Error::new(
+            native.span(),
+            "`native` only applies to `auto_sized_unsigned!`, `auto_sized_unsigned_val!`, and `auto_sized_unsigned_max!`",
+        ));
+    }
+    native_literal_width(input)
+}
+
+/// Like [`unsigned_literal_width`], but leaves the `native` flag to the caller.
+fn native_literal_width(input: &UnsignedInput) -> syn::Result<(u128, u32)> {
+    if let (Some(native), Some(hint)) = (&input.native, &input.hint) {
+        return Err(syn::Error::new(
+            native.span(),
+            format!(
+                "`native` cannot be combined with the type hint `=> {hint}`, since `usize` never matches it"
+            ),
+        ));
+    }
+    let (value, mut width) = unsigned_expr_width(&input.expr, input.width_from_digits)?;
+    if let Some(min) = &input.min {
+        width = width.max(option_width(min));
+    }
+    if let Some(max) = &input.max
+        && width > option_width(max)
+    {
+        return Err(width_cap_error(
+            &input.expr,
+            value,
+            &format!("u{width}"),
+            max,
+        ));
+    }
+    if let Some(hint) = &input.hint
+        && width != option_width(hint)
+    {
+        return Err(syn::Error::new(
+            hint.span(),
+            format!("chose `u{width}`, but the hint expects `{hint}`"),
+        ));
+    }
+    Ok((value, width))
+}
+
+/// Evaluates an unsigned expression, returning its value and the bit width of the
+/// type chosen for it.
+fn unsigned_expr_width(expr: &Expr, width_from_digits: bool) -> syn::Result<(u128, u32)> {
+    match strip_parens(expr) {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => unsigned_int_literal(lit, width_from_digits),
+        expr if width_from_digits => Err(received_error(
+            expr,
+            "`width_from_digits` only applies to radix-prefixed integer literals",
+        )),
+        expr => {
+            let value = fold_unsigned(expr)?;
+            Ok((
+                value,
+                width_for_bits(unsigned_bits_needed(value)).unwrap_or(u128::BITS),
+            ))
+        }
+    }
+}
+
+/// Parses an integer literal of an unsigned macro, honoring `width_from_digits`
+/// and treating an unsigned suffix as a minimum width.
+fn unsigned_int_literal(lit: &LitInt, width_from_digits: bool) -> syn::Result<(u128, u32)> {
+    let value = lit
+        .base10_parse::<u128>()
+        .map_err(|_| received_error(lit, UNSIGNED_OVERFLOW))?;
+    let mut bits = if width_from_digits {
+        written_bits(lit).map_err(|msg| received_error(lit, msg))?
+    } else {
+        unsigned_bits_needed(value)
+    };
+    match suffix_floor(lit)? {
+        Some((false, floor)) if floor < unsigned_bits_needed(value) => {
+            return Err(suffix_too_small(lit, value, pick_unsigned_type(value)));
+        }
+        Some((false, floor)) => bits = bits.max(floor),
+        Some((true, _)) => {
+            return Err(family_mismatch(
+                lit,
+                "with an unsigned macro; use an unsigned suffix or `auto_sized_signed!`",
+            ));
+        }
+        None => {}
+    }
+    let width = width_for_bits(bits)
+        .ok_or_else(|| received_error(lit, "has more digits than fit in u128"))?;
+    Ok((value, width))
+}
+
+/// Const-folds the input of an unsigned macro in `u128`: literals, primitive
+/// constants, parentheses, non-truncating `as` casts, and the binary operators
+/// `+ - * / % << >> & | ^`.
+fn fold_unsigned(expr: &Expr) -> syn::Result<u128> {
+    match strip_parens(expr) {
+        Expr::Binary(binary) => fold_binary(binary, fold_unsigned),
+        Expr::Unary(ExprUnary { op, .. }) if !matches!(op, UnOp::Neg(_)) => {
+            Err(unsupported_operator(op))
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(minus),
+            expr: inner,
+            ..
+        }) => {
+            let magnitude = fold_unsigned(inner)?;
+            Err(syn::Error::new(
+                minus.span,
+                format!(
+                    "requires a non-negative literal; got -{magnitude}. Negative literals are \
+                     not valid here; use `auto_sized_signed!` or `auto_sized_int!` instead"
+                ),
+            ))
+        }
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => unsigned_int_literal(lit, false).map(|(value, _)| value),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => exact_float_integer(lit).map_err(|msg| syn::Error::new(lit.span(), msg)),
+        Expr::Lit(ExprLit { lit, .. }) => scalar_value(lit)
+            .ok_or_else(|| received_error(lit, "only accepts integer, char, or byte literals")),
+        Expr::Cast(cast) => {
+            let value = fold_unsigned(&cast.expr)?;
+            let (_, max) = cast_range(&cast.ty)?;
+            if value > max {
+                return Err(truncating_cast(cast, value));
+            }
+            Ok(value)
+        }
+        Expr::Path(path) => match primitive_const(path) {
+            Ok((false, value)) => Ok(value),
+            Ok((true, _)) => Err(syn::Error::new_spanned(
+                path,
+                "only accepts non-negative constants",
+            )),
+            Err(msg) => Err(received_error(path, msg)),
+        },
+        expr => Err(received_error(
+            expr,
+            "only accepts integer, char, or byte literals",
+        )),
+    }
+}
+
+/// Const-folds the input of a signed or int macro in `i128`: literals, primitive
+/// constants, unary minus, parentheses, non-truncating `as` casts, and the
+/// binary operators `+ - * / % << >> & | ^`.
+fn fold_signed(expr: &Expr) -> syn::Result<i128> {
+    match strip_parens(expr) {
+        Expr::Binary(binary) => fold_binary(binary, fold_signed),
+        Expr::Unary(ExprUnary { op, .. }) if !matches!(op, UnOp::Neg(_)) => {
+            Err(unsupported_operator(op))
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) if matches!(strip_parens(inner), Expr::Binary(_) | Expr::Cast(_)) => fold_signed(inner)?
+            .checked_neg()
+            .ok_or_else(|| syn::Error::new_spanned(expr, FOLD_OVERFLOW)),
+        Expr::Cast(cast) => {
+            let value = fold_signed(&cast.expr)?;
+            let (min, max) = cast_range(&cast.ty)?;
+            if value < min || (value > 0 && value as u128 > max) {
+                return Err(truncating_cast(cast, value));
+            }
+            Ok(value)
+        }
+        leaf => signed_literal(leaf).map_err(|msg| received_error(leaf, msg)),
+    }
+}
+
+const FOLD_OVERFLOW: &str = "overflows while evaluating the constant expression";
+
+/// Folds both operands of `binary` with `fold` and applies its operator,
+/// rejecting unsupported operators before looking at the operands.
+fn fold_binary<T: FoldInt>(
+    binary: &ExprBinary,
+    fold: fn(&Expr) -> syn::Result<T>,
+) -> syn::Result<T> {
+    let supported = matches!(
+        binary.op,
+        BinOp::Add(_)
+            | BinOp::Sub(_)
+            | BinOp::Mul(_)
+            | BinOp::Div(_)
+            | BinOp::Rem(_)
+            | BinOp::Shl(_)
+            | BinOp::Shr(_)
+            | BinOp::BitAnd(_)
+            | BinOp::BitOr(_)
+            | BinOp::BitXor(_)
+    );
+    if !supported {
+        return Err(unsupported_operator(&binary.op));
+    }
+    let lhs = fold(&binary.left)?;
+    let rhs = fold(&binary.right)?;
+    T::fold(&binary.op, lhs, rhs).map_err(|msg| syn::Error::new_spanned(binary, msg))
+}
+
+/// Reports an operator the constant folder does not evaluate, naming it.
+fn unsupported_operator(op: &impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        op,
+        format!(
+            "does not support the operator `{}` in constant expressions; use `+ - * / % << >> & | ^`",
+            op.to_token_stream()
+        ),
+    )
+}
+
+/// Returns the range of the primitive integer type named in an `as` cast.
+fn cast_range(ty: &Type) -> syn::Result<(i128, u128)> {
+    let name = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident().map(Ident::to_string),
+        _ => None,
+    }
+    .unwrap_or_default();
+    let bits: u32 = match name.get(1..) {
+        Some(bits @ ("8" | "16" | "32" | "64" | "128")) => bits.parse().unwrap_or(128),
+        _ => 0,
+    };
+    match name.chars().next() {
+        Some('u') if bits > 0 => Ok((0, u128::MAX >> (u128::BITS - bits))),
+        Some('i') if bits > 0 => Ok((
+            i128::MIN >> (i128::BITS - bits),
+            (i128::MAX >> (i128::BITS - bits)) as u128,
+        )),
+        _ => Err(received_error(
+            ty,
+            "only accepts `as` casts to `u8`..`u128` or `i8`..`i128`",
+        )),
+    }
+}
+
+/// Reports an `as` cast that would truncate its operand rather than pass the
+/// value through unchanged.
+fn truncating_cast(cast: &ExprCast, value: impl Display) -> syn::Error {
+    let ty = cast.ty.to_token_stream();
+    syn::Error::new_spanned(
+        cast,
+        format!("{value} does not fit in `{ty}`, so the cast would truncate it"),
+    )
+}
+
+/// Checked arithmetic for const-folding macro input.
+trait FoldInt: Sized {
+    fn fold(op: &BinOp, lhs: Self, rhs: Self) -> Result<Self, &'static str>;
+}
+
+macro_rules! impl_fold_int {
+    ($($ty:ty),*) => {$(
+        impl FoldInt for $ty {
+            fn fold(op: &BinOp, lhs: Self, rhs: Self) -> Result<Self, &'static str> {
+                let shift = || u32::try_from(rhs).map_err(|_| FOLD_OVERFLOW);
+                let result = match op {
+                    BinOp::Add(_) => lhs.checked_add(rhs),
+                    BinOp::Sub(_) => lhs.checked_sub(rhs),
+                    BinOp::Mul(_) => lhs.checked_mul(rhs),
+                    BinOp::Div(_) | BinOp::Rem(_) if rhs == 0 => {
+                        return Err("divides by zero in the constant expression");
+                    }
+                    BinOp::Div(_) => lhs.checked_div(rhs),
+                    BinOp::Rem(_) => lhs.checked_rem(rhs),
+                    // Shifting bits out of the value counts as overflow.
+                    BinOp::Shl(_) => lhs
+                        .checked_shl(shift()?)
+                        .filter(|&v| v.checked_shr(shift().unwrap_or(0)) == Some(lhs)),
+                    BinOp::Shr(_) => lhs.checked_shr(shift()?),
+                    BinOp::BitAnd(_) => Some(lhs & rhs),
+                    BinOp::BitOr(_) => Some(lhs | rhs),
+                    BinOp::BitXor(_) => Some(lhs ^ rhs),
+                    _ => {
+                        return Err(
+                            "only supports `+ - * / % << >> & | ^` in constant expressions",
+                        );
+                    }
+                };
+                result.ok_or(FOLD_OVERFLOW)
+            }
+        }
+    )*};
+}
+
+impl_fold_int!(u128, i128);
+
+/// Parses the literal of a signed macro and picks its type. A signed suffix on
+/// the literal acts as a minimum width.
+fn signed_type(expr: &Expr) -> syn::Result<(i128, proc_macro2::TokenStream)> {
+    let value = fold_signed(expr)?;
+    let mut bits = signed_bits_needed(value);
+    if let Some(lit) = int_literal(expr) {
+        match suffix_floor(lit)? {
+            Some((true, floor)) if floor < bits => {
+                return Err(suffix_too_small(lit, value, pick_signed_type(value)));
+            }
+            Some((true, floor)) => bits = floor,
+            Some((false, _)) => {
+                return Err(family_mismatch(
+                    lit,
+                    "with a signed macro; use a signed suffix or `auto_sized_unsigned!`",
+                ));
+            }
+            None => {}
+        }
+    }
+    Ok((value, pick_signed_width(bits)))
+}
+
+/// Parses the literal of an int macro and picks its type, returning the value as
+/// a suffixed literal. Non-negative values are evaluated over the full `u128`
+/// range; negative values and signed suffixes select a signed type. A suffix also
+/// acts as a minimum width.
+fn int_type(expr: &Expr) -> syn::Result<(Literal, proc_macro2::TokenStream)> {
+    let signed_suffix = match int_literal(expr) {
+        Some(lit) => matches!(suffix_floor(lit)?, Some((true, _))),
+        None => false,
+    };
+    let unsigned = if signed_suffix {
+        None
+    } else {
+        match unsigned_int_type(expr) {
+            Ok(v) => return Ok(v),
+            Err(err) => Some(err),
+        }
+    };
+
+    let value = match fold_signed(expr) {
+        Ok(value) => value,
+        // Without a minus sign the input was meant to be unsigned.
+        Err(err) => match unsigned {
+            Some(unsigned) if !has_negation(expr) => return Err(unsigned),
+            _ => return Err(err),
+        },
+    };
+    let floor = match int_literal(expr) {
+        Some(lit) => suffix_floor(lit)?.map(|floor| (lit, floor)),
+        None => None,
+    };
+    let ty = match floor {
+        Some((lit, (true, floor))) if floor < signed_bits_needed(value) => {
+            return Err(suffix_too_small(lit, value, pick_signed_type(value)));
+        }
+        Some((_, (true, floor))) => pick_signed_width(floor),
+        Some((lit, (false, _))) if value < 0 => {
+            return Err(family_mismatch(
+                lit,
+                "on a negative literal; use a signed suffix",
+            ));
+        }
+        Some((lit, (false, floor))) if floor < unsigned_bits_needed(value as u128) => {
+            return Err(suffix_too_small(
+                lit,
+                value,
+                pick_unsigned_type(value as u128),
+            ));
+        }
+        Some((_, (false, floor))) => {
+            pick_unsigned_width(floor).unwrap_or_else(|| primitive("u128"))
+        }
+        None if value < 0 => pick_signed_type(value),
+        None => pick_unsigned_type(value as u128),
+    };
+    let value = int_suffixed(value, &ty).map_err(call_site_error)?;
+    Ok((value, ty))
+}
+
+/// Like [`int_type`], but an input written with a leading `+` always takes the
+/// unsigned branch.
+fn hinted_int_type(input: &IntExpr) -> syn::Result<(Literal, proc_macro2::TokenStream)> {
+    if input.plus {
+        if let Some(lit) = int_literal(&input.expr)
+            && let Some((true, _)) = suffix_floor(lit)?
+        {
+            return Err(family_mismatch(
+                lit,
+                "after a leading `+`, which asks for an unsigned type",
+            ));
+        }
+        unsigned_int_type(&input.expr)
+    } else {
+        int_type(&input.expr)
+    }
+}
+
+/// Picks the type of an int macro, widening it to the `min` option and checking
+/// it against the `max` option. Either option may name either family; only its
+/// width applies, and the value still decides between signed and unsigned.
+fn capped_int_type(input: &IntInput) -> syn::Result<(Literal, proc_macro2::TokenStream)> {
+    let (mut value, mut ty) = hinted_int_type(&input.int)?;
+    if let Some(min) = &input.min
+        && primitive_width(&ty) < option_width(min)
+    {
+        let name = primitive_name(&ty);
+        let wider = format!("{}{}", &name[..1], option_width(min));
+        value = resuffixed(&value, &name, &wider);
+        ty = primitive(wider);
+    }
+    if let Some(max) = &input.max
+        && primitive_width(&ty) > option_width(max)
+    {
+        let name = primitive_name(&ty);
+        let text = value.to_string();
+        let digits = &text[..text.len() - name.len()];
+        return Err(width_cap_error(&input.int.expr, digits, &name, max));
+    }
+    Ok((value, ty))
+}
+
+/// Replaces the `from` suffix of an integer literal with `to`.
+fn resuffixed(value: &Literal, from: &str, to: &str) -> Literal {
+    let text = value.to_string();
+    format!("{}{to}", &text[..text.len() - from.len()])
+        .parse()
+        .expect("re-suffixed integer literal")
+}
+
+/// Expands the `native` option: `native` on every target whose pointer width
+/// can hold `width` bits without exceeding the `max` option, selected with
+/// `cfg_select!`, and `fixed` elsewhere.
+fn native_select(
+    width: u32,
+    max: Option<&Ident>,
+    native: proc_macro2::TokenStream,
+    fixed: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let widths: Vec<_> = [16, 32, 64]
+        .into_iter()
+        .filter(|&bits| bits >= width && max.is_none_or(|max| bits <= option_width(max)))
+        .map(|bits| bits.to_string())
+        .collect();
+    if widths.is_empty() {
+        return fixed;
+    }
+
+    quote! {
+        ::core::cfg_select! {
+            any(#(target_pointer_width = #widths),*) => { #native }
+            _ => { #fixed }
+        }
+    }
+}
+
+/// Emits `value` suffixed with `usize` or `isize`.
+fn native_literal(value: impl Display, suffix: &str) -> Literal {
+    format!("{value}{suffix}")
+        .parse()
+        .expect("pointer-sized integer literal")
+}
+
+/// Applies the `ptr_width` option of the int macros: a type exactly as wide as
+/// the target pointer becomes `usize` or `isize`. Suffixed literals keep the
+/// type they asked for.
+fn pointer_int_type(
+    input: &IntInput,
+    (value, ty): (Literal, proc_macro2::TokenStream),
+) -> (Literal, proc_macro2::TokenStream) {
+    let Some(width) = input.ptr_width else {
+        return (value, ty);
+    };
+    if int_literal(&input.int.expr).is_some_and(|lit| !lit.suffix().is_empty()) {
+        return (value, ty);
+    }
+    let name = primitive_name(&ty);
+    let pointer = match name.strip_prefix('u').or_else(|| name.strip_prefix('i')) {
+        Some(bits) if bits == width.to_string() => format!("{}size", &name[..1]),
+        _ => return (value, ty),
+    };
+    let value = resuffixed(&value, &name, &pointer);
+    (value, primitive(pointer))
+}
+
+/// Evaluates the input of an int macro as unsigned, returning the value as a
+/// suffixed literal and its type.
+fn unsigned_int_type(expr: &Expr) -> syn::Result<(Literal, proc_macro2::TokenStream)> {
+    let (value, bits) = unsigned_expr_width(expr, false)?;
+    let ty = pick_unsigned_width(bits).unwrap_or_else(|| primitive("u128"));
+    let value = unsigned_suffixed(value, &ty).map_err(call_site_error)?;
+    Ok((value, ty))
+}
+
+/// Reports whether `expr` contains a unary minus or a subtraction.
+fn has_negation(expr: &Expr) -> bool {
+    match expr {
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_), ..
+        }) => true,
+        Expr::Binary(binary) => {
+            matches!(binary.op, BinOp::Sub(_))
+                || has_negation(&binary.left)
+                || has_negation(&binary.right)
+        }
+        Expr::Paren(ExprParen { expr: inner, .. }) | Expr::Group(ExprGroup { expr: inner, .. }) => {
+            has_negation(inner)
+        }
+        _ => false,
+    }
+}
+
+/// Finds the first integer literal in `expr` that carries a suffix, looking
+/// through unary and binary operators, parentheses, and invisible groups.
+fn suffixed_int_literal(expr: &Expr) -> Option<&LitInt> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) if !lit.suffix().is_empty() => Some(lit),
+        Expr::Unary(ExprUnary { expr: inner, .. })
+        | Expr::Paren(ExprParen { expr: inner, .. })
+        | Expr::Group(ExprGroup { expr: inner, .. }) => suffixed_int_literal(inner),
+        Expr::Binary(binary) => {
+            suffixed_int_literal(&binary.left).or_else(|| suffixed_int_literal(&binary.right))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the signedness and width named by a literal suffix, if any.
+fn suffix_floor(lit: &LitInt) -> syn::Result<Option<(bool, u32)>> {
+    let floor = match lit.suffix() {
+        "" => return Ok(None),
+        "u8" => (false, 8),
+        "u16" => (false, 16),
+        "u32" => (false, 32),
+        "u64" => (false, 64),
+        "u128" => (false, 128),
+        "i8" => (true, 8),
+        "i16" => (true, 16),
+        "i32" => (true, 32),
+        "i64" => (true, 64),
+        "i128" => (true, 128),
+        suffix => {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("does not accept the `{suffix}` suffix"),
+            ));
+        }
+    };
+    Ok(Some(floor))
+}
+
+/// Builds the error for a suffix whose signedness contradicts the macro family.
+fn family_mismatch(lit: &LitInt, context: &str) -> syn::Error {
+    let suffix = lit.suffix();
+    syn::Error::new(
+        lit.span(),
+        format!("cannot use the `{suffix}` suffix {context}"),
+    )
+}
+
+/// Builds the error for a suffix too narrow to hold the literal's value.
+fn suffix_too_small(
+    lit: &LitInt,
+    value: impl Display,
+    fits: proc_macro2::TokenStream,
+) -> syn::Error {
+    let suffix = lit.suffix();
+    syn::Error::new(
+        lit.span(),
+        format!(
+            "{value} does not fit in the `{suffix}` suffix; the smallest type that fits is `{fits}`"
+        ),
+    )
+}
+
+/// Finds the integer literal inside `expr`, looking through parentheses,
+/// invisible groups, and a leading minus sign.
+fn int_literal(expr: &Expr) -> Option<&LitInt> {
+    match strip_parens(expr) {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Some(lit),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => int_literal(inner),
+        _ => None,
+    }
+}
+
+const UNSIGNED_OVERFLOW: &str = "literal exceeds the maximum supported value (u128::MAX = 340282366920938463463374607431768211455)";
+
+const SIGNED_OVERFLOW: &str = "literal is outside the supported range \
+    (i128::MIN = -170141183460469231731687303715884105728 to \
+    i128::MAX = 170141183460469231731687303715884105727)";
+
+/// Builds an error spanning `tokens` that quotes them back in the message.
+fn received_error(tokens: &impl ToTokens, msg: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("{msg}, found `{}`", tokens.to_token_stream()),
+    )
+}
+
+fn call_site_error(msg: &str) -> syn::Error {
+    syn::Error::new(Span::call_site(), msg)
+}
+
+/// Reports a macro invoked without its integer argument by name, instead of the
+/// parser's generic end-of-input error.
+fn missing_argument(name: &str) -> TokenStream {
+    spanned_error(
+        name,
+        call_site_error("requires one integer literal argument"),
+    )
+}
+
+/// Prefixes `err` with the macro name, keeping the span it points at.
+fn spanned_error(name: &str, err: syn::Error) -> TokenStream {
+    let errors = err
+        .into_iter()
+        .map(|err| syn::Error::new(err.span(), format!("{name} {err}")).to_compile_error())
+        .collect::<Vec<_>>();
+    // Several errors are wrapped in a tuple so the expansion stays valid in both
+    // type and expression position.
+    if errors.len() == 1 {
+        errors
+            .into_iter()
+            .collect::<proc_macro2::TokenStream>()
+            .into()
+    } else {
+        quote! { (#(#errors),*) }.into()
+    }
+}
+
+/// Counts the bits spelled out by a radix-prefixed literal, leading zeros included.
+fn written_bits(lit: &LitInt) -> Result<u32, &'static str> {
+    let text = lit.to_string();
+    let text = &text[..text.len() - lit.suffix().len()];
+    let (digits, bits_per_digit) = if let Some(digits) = text.strip_prefix("0x") {
+        (digits, 4)
+    } else if let Some(digits) = text.strip_prefix("0b") {
+        (digits, 1)
+    } else {
+        return Err(
+            "`width_from_digits` only applies to radix-prefixed literals such as `0x00FF` or `0b0000_1010`",
+        );
+    };
+    Ok(digits.chars().filter(|&c| c != '_').count() as u32 * bits_per_digit)
+}
+
+/// Parses a float literal, rejecting values that overflow to infinity.
+fn float_literal(lit: &LitFloat) -> syn::Result<f64> {
+    match lit.base10_parse::<f64>() {
+        Ok(v) if v.is_finite() => Ok(v),
+        Ok(_) => Err(received_error(
+            lit,
+            "does not accept literals that overflow to infinity",
+        )),
+        Err(_) => Err(received_error(lit, "only accepts float literals")),
+    }
+}
+
+/// Picks the float type of a float literal, treating an `f64` suffix as a
+/// minimum width and rejecting an `f32` suffix on a value `f32` would round.
+fn float_literal_type(lit: &LitFloat) -> syn::Result<&'static str> {
+    let ty = pick_float_type(float_literal(lit)?);
+    match lit.suffix() {
+        "f64" => Ok("f64"),
+        "f32" if ty == "f64" => Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "{} does not fit in the `f32` suffix without rounding; the smallest type that holds it is `f64`",
+                lit.base10_digits()
+            ),
+        )),
+        _ => Ok(ty),
+    }
+}
+
+/// Picks the float type holding an integer exactly, returning the value in
+/// decimal and the type. Only the bits between the highest and lowest set bit
+/// of the magnitude count, so `2^100` fits an `f32` while `2^24 + 1` does not.
+fn int_float_type(expr: &Expr) -> syn::Result<(String, &'static str)> {
+    if let Some(lit) = suffixed_int_literal(expr) {
+        return Err(family_mismatch(
+            lit,
+            "in a float macro; use a float literal such as `1.0f64` to pick the type",
+        ));
+    }
+    let (negative, magnitude) = match fold_unsigned(expr) {
+        Ok(value) => (false, value),
+        Err(unsigned) => match fold_signed(expr) {
+            Ok(value) => (value < 0, value.unsigned_abs()),
+            // Without a minus sign the input was meant to be unsigned.
+            Err(_) if !has_negation(expr) => return Err(unsigned),
+            Err(err) => return Err(err),
+        },
+    };
+    let significant = unsigned_bits_needed(magnitude).saturating_sub(magnitude.trailing_zeros());
+    let ty = if significant <= f32::MANTISSA_DIGITS {
+        "f32"
+    } else if significant <= f64::MANTISSA_DIGITS {
+        "f64"
+    } else {
+        return Err(received_error(
+            expr,
+            &format!(
+                "needs {significant} significant bits, but `f64` holds only {} exactly and would round this value",
+                f64::MANTISSA_DIGITS
+            ),
+        ));
+    };
+    let sign = if negative { "-" } else { "" };
+    Ok((format!("{sign}{magnitude}"), ty))
+}
+
+/// Returns the largest of a list of unsigned literals.
+fn max_unsigned_literal(lits: &Punctuated<LitInt, Token![,]>) -> syn::Result<u128> {
+    if lits.is_empty() {
+        return Err(call_site_error("expects at least one integer literal"));
+    }
+    lits.iter().try_fold(0u128, |max, lit| {
+        lit.base10_parse::<u128>()
+            .map(|v| max.max(v))
+            .map_err(|_| received_error(lit, UNSIGNED_OVERFLOW))
+    })
+}
+
+/// Returns the smallest and largest of a list of signed literals.
+fn signed_literal_bounds(exprs: &Punctuated<Expr, Token![,]>) -> syn::Result<(i128, i128)> {
+    if exprs.is_empty() {
+        return Err(call_site_error("expects at least one integer literal"));
+    }
+    exprs
+        .iter()
+        .try_fold((i128::MAX, i128::MIN), |(min, max), expr| {
+            let value = signed_literal(expr).map_err(|msg| received_error(expr, msg))?;
+            Ok((min.min(value), max.max(value)))
+        })
+}
+
+/// Picks the type [`auto_sized_common!`] chooses for a list of values.
+fn common_type(exprs: &Punctuated<Expr, Token![,]>) -> syn::Result<proc_macro2::TokenStream> {
+    if exprs.is_empty() {
+        return Err(call_site_error("expects at least one integer literal"));
+    }
+    let mut low = 0i128;
+    let mut high = 0u128;
+    let mut widest = None;
+    for expr in exprs {
+        match unsigned_expr_width(expr, false) {
+            Ok((value, _)) => {
+                if value >= high {
+                    widest = Some(expr);
+                }
+                high = high.max(value);
+            }
+            Err(_) => low = low.min(fold_signed(expr)?),
+        }
+    }
+    if low == 0 {
+        return Ok(pick_unsigned_type(high));
+    }
+    let high = i128::try_from(high).map_err(|_| {
+        received_error(
+            widest.unwrap_or(&exprs[0]),
+            "is too large to share a signed type with the negative values",
+        )
+    })?;
+    Ok(pick_bounds_type(low, high))
+}
+
+/// Emits one `#[cfg]`-guarded constant per arm of [`auto_sized_cfg!`], each
+/// typed for its own literal. An arm's guard excludes every earlier predicate,
+/// so at most one constant survives, and the first active arm wins.
+fn cfg_consts(input: &CfgInput) -> syn::Result<proc_macro2::TokenStream> {
+    let (vis, name) = match &input.item {
+        Some((vis, name)) => (Some(vis), name.clone()),
+        None => (None, format_ident!("VALUE")),
+    };
+    let mut earlier = Vec::new();
+    let mut consts = Vec::new();
+    for (predicate, expr) in &input.arms {
+        let (value, ty) = int_type(expr)?;
+        let value = source_literal(value, expr);
+        consts.push(quote! {
+            #[cfg(all(#predicate, not(any(#(#earlier),*))))]
+            #vis const #name: #ty = #value;
+        });
+        earlier.push(predicate);
+    }
+    match &input.fallback {
+        Some(expr) => {
+            let (value, ty) = int_type(expr)?;
+            let value = source_literal(value, expr);
+            consts.push(quote! {
+                #[cfg(not(any(#(#earlier),*)))]
+                #vis const #name: #ty = #value;
+            });
+        }
+        None => consts.push(quote! {
+            #[cfg(not(any(#(#earlier),*)))]
+            #vis const #name: () = ::core::compile_error!(
+                "auto_sized_cfg! no arm matches the active configuration; add a `_ => literal` arm"
+            );
+        }),
+    }
+    Ok(quote! { #(#consts)* })
+}
+
+/// Builds the typed array literal [`auto_sized_array!`] expands to.
+fn array_literals(array: &ExprArray) -> syn::Result<proc_macro2::TokenStream> {
+    if array.elems.is_empty() {
+        return Err(syn::Error::new(
+            array.bracket_token.span.join(),
+            "expects at least one integer literal",
+        ));
+    }
+    let ty = common_type(&array.elems)?;
+    let elems = array
+        .elems
+        .iter()
+        .map(|expr| {
+            let value = match unsigned_expr_width(expr, false) {
+                Ok((value, _)) => value as i128,
+                Err(_) => fold_signed(expr)?,
+            };
+            let value = int_suffixed(value, &ty).map_err(call_site_error)?;
+            Ok(source_literal(value, expr))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! { [#(#elems),*] })
+}
+
+/// Returns the inclusive bounds of a range of integer literals.
+fn range_bounds(input: &RangeInput) -> syn::Result<(i128, i128)> {
+    let range = match &input.bounds {
+        RangeBounds::Range(range) => range,
+        RangeBounds::Pair(start, end) => return pair_bounds(start, end),
+    };
+    let (Some(start), Some(end)) = (&range.start, &range.end) else {
+        return Err(syn::Error::new_spanned(
+            range,
+            "expects both a low and a high bound, e.g. `0..=255`",
+        ));
+    };
+    let low = signed_literal(start).map_err(|msg| received_error(start, msg))?;
+    let high = signed_literal(end).map_err(|msg| received_error(end, msg))?;
+    let high = match range.limits {
+        RangeLimits::Closed(_) => high,
+        RangeLimits::HalfOpen(_) => high
+            .checked_sub(1)
+            .ok_or_else(|| received_error(end, SIGNED_OVERFLOW))?,
+    };
+    if low > high {
+        return Err(syn::Error::new_spanned(
+            range,
+            "does not accept empty or reversed ranges",
+        ));
+    }
+    Ok((low, high))
+}
+
+/// Evaluates the `low, high` form of [`auto_sized_range!`], pointing at both
+/// bounds when they are out of order.
+fn pair_bounds(start: &Expr, end: &Expr) -> syn::Result<(i128, i128)> {
+    let low = signed_literal(start).map_err(|msg| received_error(start, msg))?;
+    let high = signed_literal(end).map_err(|msg| received_error(end, msg))?;
+    if low > high {
+        let mut err = syn::Error::new_spanned(
+            start,
+            format!("low bound {low} is greater than the high bound {high}"),
+        );
+        err.combine(syn::Error::new_spanned(
+            end,
+            format!("high bound {high} is less than the low bound {low}"),
+        ));
+        return Err(err);
+    }
+    Ok((low, high))
+}
+
+/// Folds a list of unsigned literals with bitwise OR.
+fn or_literals(lits: &Punctuated<LitInt, Token![,]>) -> syn::Result<u128> {
+    if lits.is_empty() {
+        return Err(call_site_error(
+            "expects at least one integer literal, e.g. `auto_sized_or!(0x01, 0x80)`",
+        ));
+    }
+    lits.iter().try_fold(0u128, |acc, lit| {
+        lit.base10_parse::<u128>()
+            .map(|v| acc | v)
+            .map_err(|_| received_error(lit, UNSIGNED_OVERFLOW))
+    })
+}
+
+/// Picks the `NonZeroU*` type for an unsigned input, rejecting zero and
+/// pointing negative values to the signed form.
+fn nonzero_unsigned_type(expr: &Expr) -> syn::Result<(u128, proc_macro2::TokenStream)> {
+    if let Ok(value @ ..0) = fold_signed(expr) {
+        return Err(syn::Error::new_spanned(
+            expr,
+            format!(
+                "requires a positive literal; got {value}. Unsigned NonZero types cannot hold \
+                 negative values; use `auto_sized_nonzero_signed!` instead"
+            ),
+        ));
+    }
+    let (value, bits) = unsigned_expr_width(expr, false)?;
+    if value == 0 {
+        return Err(syn::Error::new_spanned(expr, NONZERO_ZERO));
+    }
+    let ty = pick_unsigned_width(bits).unwrap_or_else(|| primitive("u128"));
+    Ok((value, nonzero_type(&ty)))
+}
+
+/// Picks the `NonZeroI*` type for a signed input, rejecting zero.
+fn nonzero_signed_type(expr: &Expr) -> syn::Result<(i128, proc_macro2::TokenStream)> {
+    let (value, ty) = signed_type(expr)?;
+    if value == 0 {
+        return Err(syn::Error::new_spanned(expr, NONZERO_ZERO));
+    }
+    Ok((value, nonzero_type(&ty)))
+}
+
+/// Maps a primitive type emitted by [`primitive`] to its `core::num::NonZero*`
+/// counterpart, so both share one width decision.
+fn nonzero_type(ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let nonzero = format_ident!("NonZero{}", primitive_name(ty).to_uppercase());
+    quote! { ::core::num::#nonzero }
+}
+
+const NONZERO_ZERO: &str = "does not accept 0, since a NonZero type cannot hold zero";
+
+const TRUNCATED: &str = "picked a type too narrow for the value; this is a bug in autosized-num";
+
+/// Converts a float literal whose value is an exact integer, such as `1e6` or
+/// `2.0`, working on its decimal digits so that large exponents stay exact.
+fn exact_float_integer(lit: &LitFloat) -> Result<u128, &'static str> {
+    const NOT_INTEGER: &str = "float literal is not an exact integer";
+    const TOO_LARGE: &str = "float literal does not fit in 128 bits";
+    let digits = lit.base10_digits();
+    let (mantissa, exponent) = match digits.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| TOO_LARGE)?),
+        None => (digits, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mut digits = format!("{int}{frac}");
+    let mut exponent = exponent.saturating_sub(frac.len() as i32);
+    while exponent < 0 {
+        if !digits.ends_with('0') {
+            return Err(NOT_INTEGER);
+        }
+        digits.pop();
+        exponent += 1;
+    }
+    let mut value = match digits.trim_start_matches('0') {
+        "" => return Ok(0),
+        digits => digits.parse::<u128>().map_err(|_| TOO_LARGE)?,
+    };
+    for _ in 0..exponent {
+        value = value.checked_mul(10).ok_or(TOO_LARGE)?;
+    }
+    Ok(value)
+}
+
+/// Resolves `u8::MAX` through `u128::MAX` and `i8::MIN`/`i8::MAX` through
+/// `i128::MIN`/`i128::MAX` to their sign and magnitude.
+fn primitive_const(path: &ExprPath) -> Result<(bool, u128), &'static str> {
+    const UNKNOWN: &str = "only accepts the constants `u8::MAX` through `u128::MAX` and `i8::MIN`/`i8::MAX` through `i128::MIN`/`i128::MAX`";
+    const NAMED: &str = "cannot read the value of a named constant, since a macro sees only its name; write the value as a literal or as arithmetic on literals such as `100 * 3`";
+    if path.qself.is_some() || path.path.leading_colon.is_some() {
+        return Err(UNKNOWN);
+    }
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let [ty, name] = segments.as_slice() else {
+        return Err(NAMED);
+    };
+    if !matches!(
+        ty.as_str(),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+    ) {
+        return Err(NAMED);
+    }
+    let value = match (ty.as_str(), name.as_str()) {
+        ("u8", "MAX") => (false, u8::MAX as u128),
+        ("u16", "MAX") => (false, u16::MAX as u128),
+        ("u32", "MAX") => (false, u32::MAX as u128),
+        ("u64", "MAX") => (false, u64::MAX as u128),
+        ("u128", "MAX") => (false, u128::MAX),
+        ("i8", "MIN") => (true, i8::MIN.unsigned_abs() as u128),
+        ("i16", "MIN") => (true, i16::MIN.unsigned_abs() as u128),
+        ("i32", "MIN") => (true, i32::MIN.unsigned_abs() as u128),
+        ("i64", "MIN") => (true, i64::MIN.unsigned_abs() as u128),
+        ("i128", "MIN") => (true, i128::MIN.unsigned_abs()),
+        ("i8", "MAX") => (false, i8::MAX as u128),
+        ("i16", "MAX") => (false, i16::MAX as u128),
+        ("i32", "MAX") => (false, i32::MAX as u128),
+        ("i64", "MAX") => (false, i64::MAX as u128),
+        ("i128", "MAX") => (false, i128::MAX as u128),
+        _ => return Err(UNKNOWN),
+    };
+    Ok(value)
+}
+
+/// Returns the numeric value of a char or byte literal.
+fn scalar_value(lit: &Lit) -> Option<u128> {
+    match lit {
+        Lit::Char(ch) => Some(ch.value() as u128),
+        Lit::Byte(byte) => Some(byte.value() as u128),
+        _ => None,
+    }
+}
+
+/// Extracts an integer, char, or byte literal from `expr`, folding a single leading minus
+/// sign (possibly separated by parentheses or invisible groups) into the value.
+fn signed_literal(expr: &Expr) -> Result<i128, &'static str> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<i128>().map_err(|_| SIGNED_OVERFLOW),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => i128::try_from(exact_float_integer(lit)?)
+            .map_err(|_| "only accepts integer literals within the i128 range"),
+        Expr::Lit(ExprLit { lit, .. }) => scalar_value(lit)
+            .map(|v| v as i128)
+            .ok_or("only accepts integer, char, or byte literals"),
+        Expr::Path(path) => match primitive_const(path)? {
+            (true, magnitude) => 0i128.checked_sub_unsigned(magnitude),
+            (false, magnitude) => i128::try_from(magnitude).ok(),
+        }
+        .ok_or("only accepts constants within the i128 range"),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => {
+            match strip_parens(inner) {
+                Expr::Unary(ExprUnary {
+                    op: UnOp::Neg(_), ..
+                }) => return Err("does not accept double negation"),
+                Expr::Lit(ExprLit { lit, .. }) if scalar_value(lit).is_some() => {
+                    return Err("does not accept negated char or byte literals");
+                }
+                // The magnitude of `i128::MIN` only fits in `u128`.
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                }) => {
+                    let magnitude = lit.base10_parse::<u128>().map_err(|_| SIGNED_OVERFLOW)?;
+                    return 0i128.checked_sub_unsigned(magnitude).ok_or(SIGNED_OVERFLOW);
+                }
+                _ => {}
+            }
+            signed_literal(inner)?
+                .checked_neg()
+                .ok_or("only accepts integer, char, or byte literals")
+        }
+        Expr::Paren(ExprParen { expr: inner, .. }) | Expr::Group(ExprGroup { expr: inner, .. }) => {
+            signed_literal(inner)
+        }
+        _ => Err("only accepts integer, char, or byte literals"),
+    }
+}
+
+/// Removes any parentheses or invisible groups wrapped around `expr`.
+fn strip_parens(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ExprParen { expr: inner, .. }) | Expr::Group(ExprGroup { expr: inner, .. }) => {
+            strip_parens(inner)
+        }
+        _ => expr,
+    }
+}
+
+fn pick_unsigned_type(value: u128) -> proc_macro2::TokenStream {
+    primitive(format!("u{}", smallest_unsigned_bits(value)))
+}
+
+/// Picks the smallest type holding every value in `low..=high`: unsigned when
+/// `low` is non-negative, signed otherwise.
+fn pick_bounds_type(low: i128, high: i128) -> proc_macro2::TokenStream {
+    if low < 0 {
+        pick_signed_width(signed_bits_needed(low).max(signed_bits_needed(high)))
+    } else {
+        pick_unsigned_type(high as u128)
+    }
+}
+
+fn pick_unsigned_width(bits: u32) -> Option<proc_macro2::TokenStream> {
+    Some(primitive(format!("u{}", width_for_bits(bits)?)))
+}
+
+/// Expands [`auto_sized_bits!`] and its other names, reporting errors under
+/// `name`.
+fn expand_bits(name: &str, input: TokenStream) -> TokenStream {
+    let IntExpr { expr, .. } = parse_macro_input!(input as IntExpr);
+    match bits_type(&expr) {
+        Ok((_, ty)) => ty.into(),
+        Err(err) => spanned_error(name, err),
+    }
+}
+
+/// Picks the type for a bit count, returning the count and the type.
+fn bits_type(expr: &Expr) -> syn::Result<(u32, proc_macro2::TokenStream)> {
+    let bits = fold_unsigned(expr)?;
+    if bits == 0 {
+        return Err(received_error(expr, "expects at least one bit"));
+    }
+    let ty = pick_bits_type(bits, expr)?;
+    Ok((bits as u32, ty))
+}
+
+/// Picks the signed type for a bit count that excludes the sign bit.
+fn signed_bits_type(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let bits = fold_unsigned(expr)?;
+    if bits == 0 {
+        return Err(received_error(expr, "expects at least one bit"));
+    }
+    if bits >= i128::BITS as u128 {
+        return Err(syn::Error::new_spanned(
+            expr,
+            format!("needs {bits} bits plus a sign bit, but the widest type `i128` has 128"),
+        ));
+    }
+    Ok(pick_signed_width(bits as u32 + 1))
+}
+
+/// Picks the type for a byte count with the same table as [`bits_type`], returning the
+/// count in bits and the type.
+fn bytes_type(input: &BytesInput) -> syn::Result<(u32, proc_macro2::TokenStream)> {
+    let bytes = fold_unsigned(&input.expr)?;
+    let expr = &input.expr;
+    if bytes == 0 {
+        return Err(received_error(expr, "expects at least one byte"));
+    }
+    if bytes > 16 {
+        return Err(syn::Error::new_spanned(
+            expr,
+            format!("needs {bytes} bytes, but the widest type `u128` has 16"),
+        ));
+    }
+    if input.exact && !bytes.is_power_of_two() {
+        return Err(syn::Error::new_spanned(
+            expr,
+            format!("{bytes} bytes is not the size of any integer type, which `exact` requires"),
+        ));
+    }
+    let bits = bytes * 8;
+    Ok((bits as u32, pick_bits_type(bits, expr)?))
+}
+
+/// Emits a length suffixed with the type that indexes it, rejecting lengths one
+/// past that type's maximum.
+fn len_literal(expr: &Expr) -> syn::Result<Literal> {
+    let len = fold_unsigned(expr)?;
+    let ty = pick_unsigned_type(len.saturating_sub(1));
+    unsigned_suffixed(len, &ty).map_err(|_| {
+        syn::Error::new_spanned(
+            expr,
+            format!(
+                "a length of {len} indexes with `{}`, but the length itself does not fit it; use `auto_sized_unsigned_val!` to store the length",
+                primitive_name(&ty)
+            ),
+        )
+    })
+}
+
+/// Emits the largest value of `bits` bits, suffixed with `ty`.
+fn max_value_literal(bits: u32, ty: &proc_macro2::TokenStream) -> syn::Result<Literal> {
+    unsigned_suffixed(u128::MAX >> (u128::BITS - bits), ty).map_err(call_site_error)
+}
+
+/// Converts a hex digit count to bits and picks the type for it, returning both.
+fn hex_digits_type(expr: &Expr) -> syn::Result<(u32, proc_macro2::TokenStream)> {
+    let digits = fold_unsigned(expr)?;
+    if digits == 0 {
+        return Err(received_error(expr, "expects at least one digit"));
+    }
+    let bits = digits.saturating_mul(4);
+    let ty = pick_bits_type(bits, expr)?;
+    Ok((bits as u32, ty))
+}
+
+/// Picks the smallest unsigned type with at least `count` bits of capacity.
+fn pick_bits_type(count: u128, expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    u32::try_from(count)
+        .ok()
+        .and_then(pick_unsigned_width)
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                expr,
+                format!("needs {count} bits, but the widest type `u128` has 128"),
+            )
+        })
+}
+
+/// Emits the primitive type `name` as `::core::primitive::name`, which a local
+/// type alias such as `type u16 = String;` cannot shadow.
+fn primitive(name: impl AsRef<str>) -> proc_macro2::TokenStream {
+    let ty = format_ident!("{}", name.as_ref());
+    quote! { ::core::primitive::#ty }
+}
+
+/// Returns the bare name of a type emitted by [`primitive`], e.g. `u16`.
+fn primitive_name(ty: &proc_macro2::TokenStream) -> String {
+    ty.clone()
+        .into_iter()
+        .last()
+        .map(|token| token.to_string())
+        .unwrap_or_default()
+}
+
+/// Returns the bit width of a sized type emitted by [`primitive`], e.g. `16`
+/// for `u16`.
+fn primitive_width(ty: &proc_macro2::TokenStream) -> u32 {
+    primitive_name(ty)[1..].parse().expect("sized integer type")
+}
+
+/// Emits `value` as a literal suffixed with the unsigned type `ty`, e.g. `300u16`.
+///
+/// Fails instead of truncating when `value` does not fit `ty`, which would mean
+/// a type was picked too narrow.
+fn unsigned_suffixed(value: u128, ty: &proc_macro2::TokenStream) -> Result<Literal, &'static str> {
+    match primitive_name(ty).as_str() {
+        "u8" => u8::try_from(value).map(Literal::u8_suffixed),
+        "u16" => u16::try_from(value).map(Literal::u16_suffixed),
+        "u32" => u32::try_from(value).map(Literal::u32_suffixed),
+        "u64" => u64::try_from(value).map(Literal::u64_suffixed),
+        _ => Ok(Literal::u128_suffixed(value)),
+    }
+    .map_err(|_| TRUNCATED)
+}
+
+/// Emits `value` suffixed with the smallest unsigned type that can hold it.
+fn smallest_unsigned_literal(value: u128) -> syn::Result<Literal> {
+    unsigned_suffixed(value, &pick_unsigned_type(value)).map_err(call_site_error)
+}
+
+/// Gives `lit` the location of the input it was computed from, so errors about
+/// the emitted value point at the caller's literal rather than the macro
+/// invocation. The literal keeps the call-site hygiene, so lints still treat it
+/// as macro output.
+fn respan(mut lit: Literal, source: Span) -> Literal {
+    lit.set_span(Span::call_site().located_at(source));
+    lit
+}
+
+/// Rewrites `value` with the digits of the caller's literal, keeping its radix
+/// and `_` grouping, and locates it at that literal with [`respan`].
+///
+/// Only a plain, possibly negated, integer literal of the same value is reused;
+/// computed values such as `u8::MAX` or `1 << 10` keep their decimal form.
+fn source_literal(value: Literal, expr: &Expr) -> Literal {
+    let span = source_span(expr);
+    let (sign, lit) = match strip_parens(expr) {
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => ("-", strip_parens(expr)),
+        expr => ("", expr),
+    };
+    let Expr::Lit(ExprLit {
+        lit: Lit::Int(lit), ..
+    }) = lit
+    else {
+        return respan(value, span);
+    };
+    let emitted = value.to_string();
+    let suffix = match emitted.strip_prefix(&format!("{sign}{}", lit.base10_digits())) {
+        Some(suffix) if suffix.starts_with(['u', 'i']) => suffix,
+        _ => return respan(value, span),
+    };
+    let text = lit.to_string();
+    let digits = &text[..text.len() - lit.suffix().len()];
+    let value = format!("{sign}{digits}{suffix}")
+        .parse()
+        .expect("re-suffixed integer literal");
+    respan(value, span)
+}
+
+/// Returns the span of `expr`, looking through the invisible groups
+/// `macro_rules!` wraps around forwarded fragments so the span lands on the
+/// tokens the caller wrote.
+fn source_span(expr: &Expr) -> Span {
+    match expr {
+        Expr::Group(ExprGroup { expr, .. }) => source_span(expr),
+        _ => expr.span(),
+    }
+}
+
+/// Emits `value` as a literal suffixed with the signed type `ty`, e.g. `-200i16`.
+///
+/// Like [`unsigned_suffixed`], fails instead of truncating.
+fn signed_suffixed(value: i128, ty: &proc_macro2::TokenStream) -> Result<Literal, &'static str> {
+    match primitive_name(ty).as_str() {
+        "i8" => i8::try_from(value).map(Literal::i8_suffixed),
+        "i16" => i16::try_from(value).map(Literal::i16_suffixed),
+        "i32" => i32::try_from(value).map(Literal::i32_suffixed),
+        "i64" => i64::try_from(value).map(Literal::i64_suffixed),
+        _ => Ok(Literal::i128_suffixed(value)),
+    }
+    .map_err(|_| TRUNCATED)
+}
+
+/// Maps an integer type to its `core::sync::atomic` counterpart, e.g. `u16` to
+/// `AtomicU16`.
+fn atomic_type(ty: &proc_macro2::TokenStream) -> syn::Result<Ident> {
+    let ty = primitive_name(ty);
+    if ty.ends_with("128") {
+        return Err(call_site_error(&format!(
+            "needs `{ty}`, which has no atomic counterpart"
+        )));
+    }
+    Ok(format_ident!("Atomic{}", ty.to_uppercase()))
+}
+
+/// Emits `value` as a literal suffixed with `ty`, which may be signed or unsigned.
+fn int_suffixed(value: i128, ty: &proc_macro2::TokenStream) -> Result<Literal, &'static str> {
+    if primitive_name(ty).starts_with('i') {
+        signed_suffixed(value, ty)
+    } else {
+        unsigned_suffixed(value as u128, ty)
+    }
+}
+
+fn pick_float_type(value: f64) -> &'static str {
+    if value as f32 as f64 == value {
+        "f32"
+    } else {
+        "f64"
+    }
+}
+
+/// Picks the smallest signed type holding `value` with the range comparison in
+/// `autosized_num_core`, so `i128::MIN` lands on `i128` without being negated.
+fn pick_signed_type(value: i128) -> proc_macro2::TokenStream {
+    primitive(format!("i{}", smallest_signed_bits(value)))
+}
+
+fn pick_signed_width(bits: u32) -> proc_macro2::TokenStream {
+    primitive(format!("i{}", width_for_bits(bits).unwrap_or(i128::BITS)))
 }
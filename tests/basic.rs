@@ -1,6 +1,114 @@
 use autosized_num::*;
 use static_assertions::assert_type_eq_all;
 
+macro_rules! forward_signed {
+    ($x:expr) => {
+        auto_sized_signed!($x)
+    };
+}
+
+macro_rules! forward_int_val {
+    ($x:expr) => {
+        auto_sized_int_val!($x)
+    };
+}
+
+macro_rules! forward_expr {
+    ($mac:ident, $x:expr) => {
+        $mac!($x)
+    };
+}
+
+macro_rules! forward_twice {
+    ($mac:ident, $x:expr) => {
+        forward_expr!($mac, $x)
+    };
+}
+
+macro_rules! forward_literal {
+    ($mac:ident, $x:literal) => {
+        $mac!($x)
+    };
+}
+
+macro_rules! counter_type {
+    ($($x:tt)*) => {
+        auto_sized_count!($($x)*)
+    };
+}
+
+#[auto_size_consts]
+mod protocol {
+    pub const VERSION: _ = 2;
+    pub const MAX_FRAME: _ = 65535;
+    pub const OFFSET: _ = -300;
+    pub const WIDE: _ = 5u32;
+    pub const TIMEOUT: u64 = 30;
+    pub const NAME: &str = "proto";
+}
+
+#[derive(AutoRepr)]
+enum Small {
+    A,
+    B = 10,
+    C,
+}
+
+#[derive(AutoRepr)]
+#[repr(i16)]
+enum Offset {
+    Back = -200,
+    Forward = 1 << 6,
+}
+
+auto_sized_const!(MAX_FRAME, 300);
+auto_sized_const!(pub(crate) OFFSET, -200);
+auto_sized_type_alias!(SampleT, 70000);
+auto_sized_type_alias!(pub(crate) Channel, 0xFF);
+auto_sized_signed_type_alias!(Delta, -200);
+auto_sized_signed_type_alias!(pub Skew, 100);
+auto_sized_newtype!(SensorId, 300);
+auto_sized_newtype!(pub(crate) Drift, -200);
+auto_sized_newtype!(SerdeId, 300, serde);
+auto_sized_newtype!(pub SerdeDrift, -200, serde);
+
+auto_sized_struct! {
+    /// A packet sized from its field maximums.
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Packet {
+        pub a: 255,
+        b: 70000,
+        skew: -200,
+        #[allow(dead_code)]
+        len: 1 << 20,
+        tag: &'static str,
+    }
+}
+
+auto_sized_struct! {
+    struct Tagged<T: Copy> where T: Default {
+        id: 300,
+        payload: T,
+    }
+}
+auto_sized_cfg! { BUFFER_SIZE, cfg(any()) => 512, _ => 65536 }
+auto_sized_cfg! { pub(crate) SKEW, cfg(all()) => -5, cfg(test) => 1_000 }
+
+assert_fits!(300, u16);
+assert_fits!(u128::MAX, u128);
+
+const TIMEOUT_MS: auto_sized_unsigned!(5000) = 5000;
+const DRIFT: auto_sized_int!(-300) = auto_sized_int_val!(-300);
+static RETRIES: auto_sized_unsigned!(300) = auto_sized_unsigned_val!(300);
+
+struct Limits;
+
+#[auto_size_consts]
+impl Limits {
+    const SMALL: _ = 100;
+    const LARGE: _ = 70000;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -25,4 +133,1561 @@ mod test {
         assert_type_eq_all!(auto_sized_int!(1_000_000_000), u32);
         assert_eq!(auto_sized_int_val!(-100_000_000), -100_000_000i32);
     }
+
+    #[test]
+    fn negative_expr() {
+        assert_type_eq_all!(auto_sized_signed!(-128), i8);
+        assert_type_eq_all!(auto_sized_signed!(-(128)), i8);
+        assert_type_eq_all!(auto_sized_signed!(-(129)), i16);
+        assert_type_eq_all!(auto_sized_int!(-128), i8);
+        assert_eq!(auto_sized_signed_val!(-(128)), -128i8);
+        assert_eq!(auto_sized_int_val!(-(200)), -200i16);
+    }
+
+    #[test]
+    fn forwarded_negative_expr() {
+        assert_type_eq_all!(forward_signed!(-128), i8);
+        assert_type_eq_all!(forward_signed!(-200), i16);
+        assert_eq!(forward_int_val!(-200), -200i16);
+    }
+
+    #[test]
+    fn float() {
+        assert_type_eq_all!(auto_sized_float!(1.5), f32);
+        assert_type_eq_all!(auto_sized_float!(0.1), f64);
+        assert_type_eq_all!(auto_sized_float!(1e38), f64);
+        assert_type_eq_all!(auto_sized_float!(3.4028234663852886e38), f32);
+        // Smallest positive f32 subnormal survives the round trip.
+        assert_type_eq_all!(auto_sized_float!(1.401298464324817e-45), f32);
+        // Smallest positive f64 subnormal flushes to zero in f32.
+        assert_type_eq_all!(auto_sized_float!(5e-324), f64);
+        assert_eq!(auto_sized_float_val!(1.5), 1.5f32);
+        assert_eq!(auto_sized_float_val!(0.1), 0.1f64);
+    }
+
+    #[test]
+    fn float_suffix() {
+        assert_type_eq_all!(auto_sized_float!(0.5f64), f64);
+        assert_type_eq_all!(auto_sized_float!(0.5f32), f32);
+        assert_type_eq_all!(auto_sized_float!(0.1f64), f64);
+        assert_eq!(auto_sized_float_val!(0.5f64), 0.5f64);
+        assert_eq!(auto_sized_float_val!(1.5f32), 1.5f32);
+        assert_eq!(auto_sized_float_val!(1e-3f64), 1e-3f64);
+    }
+
+    // 2^24 and 2^53 are powers of two, so one set bit fits `f32`; the odd
+    // neighbours above them need 25 and 54 significant bits.
+    #[test]
+    fn float_val_boundaries() {
+        assert_eq!(auto_sized_float_val!(16_777_215), 16_777_215f32);
+        assert_eq!(auto_sized_float_val!(16_777_216), 16_777_216f32);
+        assert_eq!(auto_sized_float_val!(16_777_217), 16_777_217f64);
+        assert_eq!(auto_sized_float_val!(-16_777_215), -16_777_215f32);
+        assert_eq!(auto_sized_float_val!(-16_777_216), -16_777_216f32);
+        assert_eq!(auto_sized_float_val!(-16_777_217), -16_777_217f64);
+
+        assert_eq!(
+            auto_sized_float_val!(9_007_199_254_740_991),
+            9_007_199_254_740_991f64
+        );
+        assert_eq!(
+            auto_sized_float_val!(9_007_199_254_740_992),
+            9_007_199_254_740_992f32
+        );
+        assert_eq!(
+            auto_sized_float_val!(-9_007_199_254_740_991),
+            -9_007_199_254_740_991f64
+        );
+        assert_eq!(
+            auto_sized_float_val!(-9_007_199_254_740_992),
+            -9_007_199_254_740_992f32
+        );
+        assert_eq!(
+            auto_sized_float_val!(9_007_199_254_740_994),
+            9_007_199_254_740_994f64
+        );
+
+        const EDGE: f64 = auto_sized_float_val!(-16_777_217);
+        assert_eq!(EDGE, -16_777_217.0);
+        let kind = match 16_777_217.0 {
+            auto_sized_float_val!(-16_777_217) => "negative edge",
+            auto_sized_float_val!(16_777_217) => "positive edge",
+            _ => "other",
+        };
+        assert_eq!(kind, "positive edge");
+        assert!(matches!(
+            -16_777_216.0f32,
+            auto_sized_float_val!(-16_777_216)
+        ));
+    }
+
+    #[test]
+    fn float_from_integer() {
+        assert_type_eq_all!(auto_sized_float!(0), f32);
+        assert_type_eq_all!(auto_sized_float!(16_777_216), f32);
+        assert_type_eq_all!(auto_sized_float!(16_777_217), f64);
+        assert_type_eq_all!(auto_sized_float!(-16_777_216), f32);
+        assert_type_eq_all!(auto_sized_float!(-16_777_217), f64);
+        // Trailing zero bits are free: 2^100 and 0xFFFFFF << 104 need 1 and 24 bits.
+        assert_type_eq_all!(auto_sized_float!(1 << 100), f32);
+        assert_type_eq_all!(auto_sized_float!(0xFF_FFFF << 104), f32);
+        assert_type_eq_all!(auto_sized_float!(9_007_199_254_740_992), f32);
+        assert_type_eq_all!(auto_sized_float!(9_007_199_254_740_991), f64);
+        assert_type_eq_all!(auto_sized_float!(i128::MIN), f32);
+
+        assert_eq!(auto_sized_float_val!(16_777_216), 16_777_216f32);
+        assert_eq!(auto_sized_float_val!(-16_777_217), -16_777_217f64);
+        assert_eq!(
+            auto_sized_float_val!(9_007_199_254_740_991),
+            9_007_199_254_740_991f64
+        );
+        const HALF: f32 = auto_sized_float_val!(1 << 23);
+        assert_eq!(HALF, 8_388_608.0);
+    }
+
+    #[test]
+    fn radix_prefixed() {
+        assert_type_eq_all!(auto_sized_unsigned!(0xFF), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0xFFFF), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(0x1_0000), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(0o777), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(0b1111_1111), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0b1_0000_0000), u16);
+        assert_type_eq_all!(
+            auto_sized_unsigned!(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF),
+            u128
+        );
+        assert_eq!(auto_sized_unsigned_val!(0x1_0000), 0x1_0000u32);
+        assert_eq!(auto_sized_unsigned_val!(0o17), 15u8);
+        assert_eq!(auto_sized_unsigned_val!(0b1010), 10u8);
+    }
+
+    #[test]
+    fn hex_width_from_digits() {
+        assert_type_eq_all!(auto_sized_unsigned!(0x00FF), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0xFF, width_from_digits), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0x00FF, width_from_digits), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(0x0_0FF, width_from_digits), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(0x0000_0001, width_from_digits), u32);
+        assert_type_eq_all!(
+            auto_sized_unsigned!(0x0000_0000_0000_0001, width_from_digits),
+            u64
+        );
+        assert_type_eq_all!(
+            auto_sized_unsigned!(0x0000_0000_0000_0000_0000_0000_0000_0001, width_from_digits),
+            u128
+        );
+        assert_type_eq_all!(auto_sized_unsigned!(0x1, width_from_digits), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0x00001, width_from_digits), u32);
+        assert_eq!(auto_sized_unsigned_val!(0x00FF, width_from_digits), 0xFFu16);
+    }
+
+    #[test]
+    fn binary_width_from_digits() {
+        assert_type_eq_all!(auto_sized_unsigned!(0b0000_1010, width_from_digits), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(0b1, width_from_digits), u8);
+        assert_type_eq_all!(
+            auto_sized_unsigned!(0b0000_0000_0000_0001, width_from_digits),
+            u16
+        );
+        assert_type_eq_all!(auto_sized_unsigned!(0b0_0000_0001, width_from_digits), u16);
+        assert_eq!(
+            auto_sized_unsigned_val!(0b0000_0000_0000_0001, width_from_digits),
+            1u16
+        );
+        assert_eq!(
+            auto_sized_unsigned_val!(0b0000_1010, width_from_digits),
+            10u8
+        );
+    }
+
+    #[test]
+    fn suffix_floor() {
+        assert_type_eq_all!(auto_sized_unsigned!(10u8), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(10u32), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(0x00FFu32, width_from_digits), u32);
+        assert_eq!(auto_sized_unsigned_val!(10u64), 10u64);
+        assert_eq!(auto_sized_unsigned_val!(5u32), 5u32);
+        assert_type_eq_all!(auto_sized_signed!(300i64), i64);
+        assert_type_eq_all!(auto_sized_signed!(-5i16), i16);
+        assert_type_eq_all!(auto_sized_signed!(-(5i32)), i32);
+        assert_eq!(auto_sized_signed_val!(-200i64), -200i64);
+        assert_type_eq_all!(auto_sized_int!(5u32), u32);
+        assert_type_eq_all!(auto_sized_int!(5i32), i32);
+        assert_type_eq_all!(auto_sized_int!(-5i64), i64);
+        assert_eq!(auto_sized_int_val!(5u16), 5u16);
+    }
+
+    #[test]
+    fn nonzero() {
+        use core::num::{NonZeroI8, NonZeroI16, NonZeroI64, NonZeroU8, NonZeroU16, NonZeroU32};
+
+        assert_type_eq_all!(auto_sized_nonzero_unsigned!(1), NonZeroU8);
+        assert_type_eq_all!(auto_sized_nonzero_unsigned!(300), NonZeroU16);
+        assert_type_eq_all!(auto_sized_nonzero_unsigned!(70000), NonZeroU32);
+        assert_type_eq_all!(auto_sized_nonzero_signed!(-128), NonZeroI8);
+        assert_type_eq_all!(auto_sized_nonzero_signed!(-200), NonZeroI16);
+        assert_eq!(
+            auto_sized_nonzero_unsigned_val!(300),
+            NonZeroU16::new(300).unwrap()
+        );
+        assert_eq!(
+            auto_sized_nonzero_signed_val!(-200),
+            NonZeroI16::new(-200).unwrap()
+        );
+
+        assert_type_eq_all!(auto_sized_nonzero!(300), NonZeroU16);
+        assert_type_eq_all!(auto_sized_nonzero!(1 << 16), NonZeroU32);
+        assert_type_eq_all!(auto_sized_nonzero!(10u16), NonZeroU16);
+        assert_type_eq_all!(auto_sized_nonzero!(u8::MAX), NonZeroU8);
+        assert_type_eq_all!(auto_sized_nonzero_signed!(i64::MIN), NonZeroI64);
+        assert_type_eq_all!(auto_sized_nonzero_signed!(-(64 * 2)), NonZeroI8);
+        assert_eq!(auto_sized_nonzero_val!(300), NonZeroU16::new(300).unwrap());
+        const ID: NonZeroU8 = auto_sized_nonzero_val!(7);
+        assert_eq!(ID.get(), 7);
+
+        const SENTINEL: auto_sized_nonzero!(300) = auto_sized_nonzero_val!(300);
+        static FLOOR: auto_sized_nonzero_signed!(-200) = auto_sized_nonzero_signed_val!(-200);
+        const MIN: auto_sized_nonzero_signed!(i128::MIN) =
+            auto_sized_nonzero_signed_val!(-170141183460469231731687303715884105728);
+        assert_eq!(SENTINEL.get(), 300u16);
+        assert_eq!(FLOOR.get(), -200i16);
+        assert_eq!(MIN.get(), i128::MIN);
+    }
+
+    #[test]
+    fn or() {
+        assert_type_eq_all!(auto_sized_or!(0x01, 0x80, 0x4000), u16);
+        assert_type_eq_all!(auto_sized_or!(0x80, 0x80, 0b1000_0000), u8);
+        assert_type_eq_all!(auto_sized_or!(0xF0, 0x0F,), u8);
+        assert_type_eq_all!(auto_sized_or!(1, 0x1_0000), u32);
+        assert_eq!(auto_sized_or_val!(0x01, 0x80, 0x4000), 0x4081u16);
+        assert_eq!(auto_sized_or_val!(0xF0, 0xFF), 0xFFu8);
+    }
+
+    #[test]
+    fn pointer_width() {
+        assert_type_eq_all!(auto_sized_usize!(0), usize);
+        assert_type_eq_all!(auto_sized_usize!(65535), usize);
+        assert_type_eq_all!(auto_sized_usize!(5_000_000_000, assume_64bit), usize);
+        assert_type_eq_all!(auto_sized_isize!(-32768), isize);
+        assert_type_eq_all!(auto_sized_isize!(32767), isize);
+        assert_type_eq_all!(auto_sized_isize!(-5_000_000_000, assume_64bit), isize);
+    }
+
+    #[test]
+    fn width_consts() {
+        const WIDTH: usize = auto_sized_bit_width!(300);
+        assert_eq!(WIDTH, 16);
+        assert_eq!(auto_sized_bit_width!(0), 8);
+        assert_eq!(auto_sized_bit_width!(70000), 32);
+        assert_eq!(auto_sized_bit_width!(0x00FF, width_from_digits), 16);
+        assert_eq!(auto_sized_byte_width!(300), 2);
+        assert_eq!(auto_sized_byte_width!(18_446_744_073_709_551_615), 8);
+        let table = [0u8; auto_sized_byte_width!(70000)];
+        assert_eq!(table.len(), 4);
+    }
+
+    #[test]
+    fn char_literal() {
+        const A: auto_sized_unsigned!('A') = auto_sized_unsigned_val!('A');
+        assert_eq!(A, 65u8);
+        assert_type_eq_all!(auto_sized_unsigned!('é'), u8);
+        assert_type_eq_all!(auto_sized_unsigned!('€'), u16);
+        assert_type_eq_all!(auto_sized_unsigned!('😀'), u32);
+        assert_type_eq_all!(auto_sized_signed!('A'), i8);
+        assert_type_eq_all!(auto_sized_signed!('é'), i16);
+        assert_eq!(auto_sized_signed_val!('€'), 0x20ACi16);
+        assert_type_eq_all!(auto_sized_int!('A'), u8);
+        assert_eq!(auto_sized_int_val!('\n'), 10u8);
+    }
+
+    #[test]
+    fn common_type() {
+        assert_type_eq_all!(auto_sized_unsigned_all!(10, 300, 70000), u32);
+        assert_type_eq_all!(auto_sized_unsigned_all!(10, 20,), u8);
+        assert_type_eq_all!(auto_sized_unsigned_all!(0xFFFF), u16);
+        assert_type_eq_all!(auto_sized_signed_all!(-200, 10, 100), i16);
+        assert_type_eq_all!(auto_sized_signed_all!(-128, 127), i8);
+        assert_type_eq_all!(auto_sized_signed_all!(-1, 128), i16);
+        assert_type_eq_all!(auto_sized_signed_all!(-70000), i32);
+    }
+
+    #[test]
+    fn byte_literal() {
+        assert_type_eq_all!(auto_sized_unsigned!(b'\xFF'), u8);
+        assert_type_eq_all!(auto_sized_signed!(b'\xFF'), i16);
+        assert_type_eq_all!(auto_sized_int!(b'A'), u8);
+        assert_eq!(auto_sized_int_val!(b' '), 32u8);
+        assert_eq!(auto_sized_unsigned_val!(b'\0'), 0u8);
+        assert_eq!(auto_sized_unsigned_val!(b'\t'), 9u8);
+        assert_eq!(auto_sized_unsigned_val!(b'\x7F'), 127u8);
+        assert_eq!(auto_sized_signed_val!(b'\x7F'), 127i8);
+        assert_eq!(auto_sized_signed_val!(b'\n'), 10i8);
+        assert_eq!(auto_sized_unsigned_val!(b'A'), 65u8);
+        assert_type_eq_all!(auto_sized_unsigned!(b'A' => u8), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(b'z' + 200), u16);
+        assert_type_eq_all!(auto_sized_range!(b'a'..=b'z'), u8);
+        assert_eq!(auto_sized_unsigned_max!(b'A'), u8::MAX);
+        assert_eq!(
+            auto_sized_wrapping_unsigned_val!(b'A'),
+            core::num::Wrapping(65u8)
+        );
+        assert_eq!(auto_sized_float_val!(b'A'), 65f32);
+        assert_eq!(auto_sized_nonzero_unsigned_val!(b'A').get(), 65u8);
+    }
+
+    #[test]
+    fn range() {
+        assert_type_eq_all!(auto_sized_range!(0..=255), u8);
+        assert_type_eq_all!(auto_sized_range!(0..256), u8);
+        assert_type_eq_all!(auto_sized_range!(0..=256), u16);
+        assert_type_eq_all!(auto_sized_range!(-1..=127), i8);
+        assert_type_eq_all!(auto_sized_range!(-1..=128), i16);
+        assert_type_eq_all!(auto_sized_range!(-5..=200), i16);
+        assert_type_eq_all!(auto_sized_range!(-70000..0), i32);
+        assert_type_eq_all!(auto_sized_range!(10..=10), u8);
+        assert_type_eq_all!(auto_sized_range!(-40, 125), i8);
+        assert_type_eq_all!(auto_sized_range!(0, 40000), u16);
+        assert_type_eq_all!(auto_sized_range!(0, 40000, signed), i32);
+        assert_type_eq_all!(auto_sized_range!(0, 127, signed,), i8);
+        assert_type_eq_all!(auto_sized_range!(0..=255, signed), i16);
+        assert_type_eq_all!(auto_sized_range!(-1, 200, signed), i16);
+    }
+
+    #[test]
+    fn integral_float() {
+        assert_type_eq_all!(auto_sized_unsigned!(1e6), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(2.0), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(2.5e2), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(1e30), u128);
+        assert_type_eq_all!(auto_sized_unsigned!(1_000.000e-3), u8);
+        assert_eq!(
+            auto_sized_unsigned_val!(1e30),
+            1_000_000_000_000_000_000_000_000_000_000u128
+        );
+        assert_type_eq_all!(auto_sized_signed!(-1e3), i16);
+        assert_type_eq_all!(auto_sized_int!(-2.0), i8);
+        assert_eq!(auto_sized_int_val!(2.0), 2u8);
+        assert_eq!(auto_sized_int_val!(6.5536e4), 65536u32);
+    }
+
+    #[test]
+    fn suffixed_values() {
+        const A: u16 = auto_sized_unsigned_val!(300);
+        const B: i16 = auto_sized_signed_val!(-200);
+        const C: i8 = auto_sized_int_val!(-(128));
+        const D: u128 = auto_sized_int_val!(170141183460469231731687303715884105727);
+        assert_eq!((A, B, C, D), (300, -200, -128, i128::MAX as u128));
+        assert_eq!(10 - auto_sized_signed_val!(-5), 15i8);
+    }
+
+    #[test]
+    fn primitive_consts() {
+        assert_type_eq_all!(auto_sized_unsigned!(u16::MAX), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(u128::MAX), u128);
+        assert_type_eq_all!(auto_sized_unsigned!(i64::MAX), u64);
+        assert_eq!(auto_sized_unsigned_val!(u32::MAX), u32::MAX);
+        assert_type_eq_all!(auto_sized_signed!(i32::MIN), i32);
+        assert_type_eq_all!(auto_sized_signed!(i8::MAX), i8);
+        assert_type_eq_all!(auto_sized_signed!(u8::MAX), i16);
+        assert_eq!(auto_sized_signed_val!(i16::MIN), i16::MIN);
+        assert_type_eq_all!(auto_sized_int!(i64::MIN), i64);
+        assert_type_eq_all!(auto_sized_int!(u64::MAX), u64);
+        assert_eq!(auto_sized_int_val!(-(i8::MAX)), -127i8);
+    }
+
+    #[test]
+    fn const_generic_len() {
+        struct Buffer<const N: usize>([u8; N]);
+
+        let header = [0u8; auto_sized_byte_width!(300)];
+        let buffer = Buffer::<{ auto_sized_byte_width!(70000) }>([0; 4]);
+        const LEN: usize = const { auto_sized_byte_width!(u64::MAX) };
+        assert_eq!(header.len(), 2);
+        assert_eq!(buffer.0.len(), 4);
+        assert_eq!(LEN, 8);
+    }
+
+    #[test]
+    fn attribute_consts() {
+        assert_eq!(protocol::VERSION, 2u8);
+        assert_eq!(protocol::MAX_FRAME, 65535u16);
+        assert_eq!(protocol::OFFSET, -300i16);
+        assert_eq!(protocol::WIDE, 5u32);
+        assert_eq!(protocol::TIMEOUT, 30u64);
+        assert_eq!(protocol::NAME, "proto");
+        assert_eq!(Limits::SMALL, 100u8);
+        assert_eq!(Limits::LARGE, 70000u32);
+    }
+
+    #[test]
+    fn const_folding() {
+        assert_type_eq_all!(auto_sized_unsigned!(1 << 20), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(u32::MAX + 1), u64);
+        assert_type_eq_all!(auto_sized_unsigned!((0xFF00 | 0x00FF) & 0x0FFF), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(1000 / 4 % 256), u8);
+        assert_eq!(auto_sized_unsigned_val!(2 * 150), 300u16);
+        assert_type_eq_all!(auto_sized_int!(3 * 1024 * 1024), u32);
+        assert_type_eq_all!(auto_sized_int!(10 - 300), i16);
+        assert_type_eq_all!(auto_sized_signed!(-(64 * 2)), i8);
+        assert_type_eq_all!(auto_sized_signed!(-(64 * 2) - 1), i16);
+        assert_type_eq_all!(auto_sized_signed!(i32::MIN >> 16), i16);
+        assert_eq!(auto_sized_int_val!(1 ^ 3), 2u8);
+        assert_eq!(auto_sized_signed_val!(-7 / 2), -3i8);
+        assert_type_eq_all!(auto_sized_unsigned!(100 * 3), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(1 << 10), u16);
+        assert_type_eq_all!(auto_sized_int!(1 << 7), u8);
+        assert_eq!(auto_sized_unsigned_val!(256 - 1 + 0), 255u8);
+    }
+
+    #[test]
+    fn auto_repr() {
+        assert_eq!(u8::from(Small::A), 0);
+        assert_eq!(u8::from(Small::B), 10);
+        assert_eq!(u8::from(Small::C), 11);
+        assert_eq!(i16::from(Offset::Back), -200);
+        assert_eq!(i16::from(Offset::Forward), 64);
+    }
+
+    #[test]
+    fn env() {
+        assert_type_eq_all!(auto_sized_env!("AUTOSIZED_TEST_LIMIT"), u16);
+        assert_type_eq_all!(auto_sized_env!("AUTOSIZED_TEST_MASK"), u8);
+        assert_type_eq_all!(auto_sized_env!("AUTOSIZED_TEST_LIMIT", signed), i16);
+        assert_type_eq_all!(auto_sized_env!("AUTOSIZED_TEST_OFFSET", int), i16);
+        assert_eq!(auto_sized_env_val!("AUTOSIZED_TEST_LIMIT"), 2000u16);
+        const LIMIT: u16 = auto_sized_env_val!("AUTOSIZED_TEST_LIMIT");
+        assert_eq!(LIMIT, 2000);
+        assert_eq!(
+            auto_sized_env_val!("AUTOSIZED_TEST_OFFSET", signed),
+            -200i16
+        );
+    }
+
+    #[test]
+    fn forwarded_fragments() {
+        assert_type_eq_all!(forward_expr!(auto_sized_unsigned, 300), u16);
+        assert_type_eq_all!(forward_expr!(auto_sized_signed, -200), i16);
+        assert_type_eq_all!(forward_expr!(auto_sized_int, 300), u16);
+        assert_type_eq_all!(forward_expr!(auto_sized_int, -200), i16);
+        assert_eq!(forward_expr!(auto_sized_unsigned_val, 300), 300u16);
+        assert_eq!(forward_expr!(auto_sized_signed_val, -200), -200i16);
+        assert_eq!(forward_expr!(auto_sized_int_val, 300), 300u16);
+
+        assert_type_eq_all!(forward_literal!(auto_sized_unsigned, 300), u16);
+        assert_type_eq_all!(forward_literal!(auto_sized_signed, -200), i16);
+        assert_type_eq_all!(forward_literal!(auto_sized_int, -200), i16);
+        assert_eq!(forward_literal!(auto_sized_unsigned_val, 300), 300u16);
+        assert_eq!(forward_literal!(auto_sized_signed_val, -200), -200i16);
+        assert_eq!(forward_literal!(auto_sized_int_val, -200), -200i16);
+
+        assert_type_eq_all!(forward_twice!(auto_sized_unsigned, 300), u16);
+        assert_eq!(forward_twice!(auto_sized_signed_val, -200), -200i16);
+    }
+
+    #[test]
+    fn wrapping() {
+        use core::num::Wrapping;
+
+        assert_type_eq_all!(auto_sized_wrapping!(300), Wrapping<u16>);
+        assert_type_eq_all!(auto_sized_wrapping!(-200), Wrapping<i16>);
+        assert_eq!(auto_sized_wrapping_val!(300), Wrapping(300u16));
+        assert_eq!(auto_sized_wrapping_val!(-200), Wrapping(-200i16));
+        assert_eq!(auto_sized_wrapping_val!(255) + Wrapping(1), Wrapping(0u8));
+        assert_eq!(auto_sized_wrapping_val!(5i16), Wrapping(5i16));
+
+        assert_type_eq_all!(auto_sized_wrapping_unsigned!(40_000), Wrapping<u16>);
+        assert_type_eq_all!(auto_sized_wrapping_unsigned!(10, min = u32), Wrapping<u32>);
+        assert_type_eq_all!(auto_sized_wrapping_signed!(200), Wrapping<i16>);
+        assert_type_eq_all!(auto_sized_wrapping_signed!(-5), Wrapping<i8>);
+
+        static SEQ: auto_sized_wrapping_unsigned!(65_535) =
+            auto_sized_wrapping_unsigned_val!(65_535);
+        assert_eq!(SEQ + Wrapping(1), Wrapping(0u16));
+        const PHASE: auto_sized_wrapping_signed!(127) = auto_sized_wrapping_signed_val!(127);
+        assert_eq!(PHASE + Wrapping(1), Wrapping(i8::MIN));
+        const BACK: auto_sized_wrapping!(-200) = auto_sized_wrapping_val!(-200);
+        assert_eq!(BACK.0, -200i16);
+    }
+
+    // Wrapping must never change the width the plain macros pick.
+    #[test]
+    fn wrapping_widths() {
+        use core::mem::size_of;
+        use core::num::Wrapping;
+
+        macro_rules! same_width {
+            ($($value:expr),*) => {$(
+                assert_type_eq_all!(auto_sized_wrapping!($value), Wrapping<auto_sized_int!($value)>);
+                assert_type_eq_all!(
+                    auto_sized_wrapping_unsigned!($value),
+                    Wrapping<auto_sized_unsigned!($value)>
+                );
+                assert_type_eq_all!(
+                    auto_sized_wrapping_signed!($value),
+                    Wrapping<auto_sized_signed!($value)>
+                );
+                assert_eq!(
+                    size_of::<auto_sized_wrapping_unsigned!($value)>(),
+                    size_of::<auto_sized_unsigned!($value)>()
+                );
+            )*};
+        }
+
+        same_width!(
+            0,
+            127,
+            128,
+            255,
+            256,
+            32_767,
+            32_768,
+            65_535,
+            65_536,
+            u64::MAX
+        );
+        assert_eq!(auto_sized_int_val!(5i16), 5i16);
+    }
+
+    #[test]
+    fn leading_plus() {
+        assert_type_eq_all!(auto_sized_unsigned!(+300), u16);
+        assert_type_eq_all!(auto_sized_signed!(+10), i8);
+        assert_type_eq_all!(auto_sized_int!(+10), u8);
+        assert_type_eq_all!(auto_sized_int!(+0x1_0000), u32);
+        assert_eq!(auto_sized_unsigned_val!(+300), 300u16);
+        assert_eq!(auto_sized_signed_val!(+200), 200i16);
+        assert_eq!(auto_sized_int_val!(+10), 10u8);
+        assert_eq!(auto_sized_int_val!(+2 * 100), 200u8);
+    }
+
+    #[test]
+    fn atomic() {
+        use core::sync::atomic::{
+            AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicU8, AtomicU16, AtomicU32, AtomicU64,
+            Ordering,
+        };
+
+        assert_type_eq_all!(auto_sized_atomic!(255), AtomicU8);
+        assert_type_eq_all!(auto_sized_atomic!(300), AtomicU16);
+        assert_type_eq_all!(auto_sized_atomic!(-200), AtomicI16);
+        assert_type_eq_all!(auto_sized_atomic!(u64::MAX), AtomicU64);
+
+        static COUNTER: AtomicU16 = auto_sized_atomic_val!(300);
+        assert_eq!(COUNTER.fetch_add(1, Ordering::Relaxed), 300);
+        let offset = auto_sized_atomic_val!(-200);
+        assert_eq!(offset.load(Ordering::Relaxed), -200i16);
+
+        assert_type_eq_all!(auto_sized_atomic!(50_000), AtomicU16);
+        assert_type_eq_all!(auto_sized_atomic!(255, min = 32), AtomicU32);
+        assert_type_eq_all!(auto_sized_atomic!(-200, min = 64), AtomicI64);
+        assert_type_eq_all!(auto_sized_atomic!(u64::MAX, min = 16), AtomicU64);
+        assert_type_eq_all!(auto_sized_atomic!(255, min = 8,), AtomicU8);
+        static WIDE: AtomicU32 = auto_sized_atomic_val!(50_000, min = 32);
+        assert_eq!(WIDE.load(Ordering::Relaxed), 50_000u32);
+
+        assert_type_eq_all!(auto_sized_atomic_signed!(100), AtomicI8);
+        assert_type_eq_all!(auto_sized_atomic_signed!(200), AtomicI16);
+        assert_type_eq_all!(auto_sized_atomic_signed!(-5, min = 32), AtomicI32);
+        static DELTA: AtomicI32 = auto_sized_atomic_signed_val!(200, min = 32);
+        assert_eq!(DELTA.fetch_sub(400, Ordering::Relaxed), 200);
+        assert_eq!(DELTA.load(Ordering::Relaxed), -200);
+    }
+
+    #[test]
+    fn atomic_pairs() {
+        use core::sync::atomic::Ordering;
+
+        macro_rules! paired {
+            ($($name:ident = $value:expr $(, $min:ident = $floor:literal)?;)*) => {$(
+                static $name: auto_sized_atomic!($value $(, $min = $floor)?) =
+                    auto_sized_atomic_val!($value $(, $min = $floor)?);
+                assert_eq!($name.load(Ordering::Relaxed), $value);
+            )*};
+        }
+
+        paired! {
+            U8_MAX = 255;
+            U16_MIN = 256;
+            U16_MAX = 65535;
+            U32_MIN = 65536;
+            I8_MIN = -128;
+            I16_MIN = -129;
+            FLOORED = 255, min = 32;
+        }
+        static HIGH_WATER: auto_sized_atomic!(50_000) = auto_sized_atomic_val!(50_000);
+        assert_eq!(HIGH_WATER.fetch_max(60_000, Ordering::Relaxed), 50_000);
+        static DELTA: auto_sized_atomic_signed!(255) = auto_sized_atomic_signed_val!(255);
+        assert_eq!(DELTA.load(Ordering::Relaxed), 255);
+    }
+
+    #[test]
+    fn atomic_fallback() {
+        #[cfg(target_has_atomic = "16")]
+        type Counter = auto_sized_atomic!(50_000);
+        #[cfg(not(target_has_atomic = "16"))]
+        type Counter = auto_sized_atomic!(50_000, min = 32);
+
+        let counter: Counter = Counter::new(auto_sized_unsigned_val!(50_000));
+        assert_eq!(counter.load(core::sync::atomic::Ordering::Relaxed), 50_000);
+    }
+
+    #[test]
+    fn signed_minimum_boundaries() {
+        assert_type_eq_all!(
+            auto_sized_signed!(-170141183460469231731687303715884105728),
+            i128
+        );
+        assert_type_eq_all!(
+            auto_sized_signed!(-170141183460469231731687303715884105727),
+            i128
+        );
+        assert_type_eq_all!(auto_sized_signed!(-9223372036854775808), i64);
+        assert_type_eq_all!(auto_sized_signed!(-9223372036854775809), i128);
+        assert_type_eq_all!(
+            auto_sized_int!(-170141183460469231731687303715884105728),
+            i128
+        );
+        assert_type_eq_all!(auto_sized_int!(-9223372036854775808), i64);
+
+        assert_eq!(
+            auto_sized_signed_val!(-170141183460469231731687303715884105728),
+            i128::MIN
+        );
+        assert_eq!(
+            auto_sized_signed_val!(-170141183460469231731687303715884105727),
+            i128::MIN + 1
+        );
+        assert_eq!(auto_sized_signed_val!(-9223372036854775808), i64::MIN);
+        assert_eq!(
+            auto_sized_int_val!(-170141183460469231731687303715884105728),
+            i128::MIN
+        );
+        assert_eq!(auto_sized_int_val!(-9223372036854775808), i64::MIN);
+
+        // i128::MIN has no positive counterpart, so it must not go through an
+        // absolute value or the unsigned branch of the int macros.
+        assert_type_eq_all!(auto_sized_int!(i128::MIN), i128);
+        assert_type_eq_all!(auto_sized_signed!(i128::MIN), i128);
+        assert_type_eq_all!(auto_sized_int!(-i128::MAX - 1), i128);
+        assert_type_eq_all!(
+            auto_sized_int!(-0x8000_0000_0000_0000_0000_0000_0000_0000),
+            i128
+        );
+        assert_eq!(auto_sized_int_val!(i128::MIN), i128::MIN);
+        assert_eq!(auto_sized_int_val!(-i128::MAX - 1), i128::MIN);
+        assert_eq!(
+            auto_sized_int_val!(-0x8000_0000_0000_0000_0000_0000_0000_0000),
+            i128::MIN
+        );
+    }
+
+    // The value macros emit one negative literal token, so a method call applies
+    // to the whole value instead of binding before the minus sign.
+    #[test]
+    #[deny(overflowing_literals)]
+    fn negative_literal_tokens() {
+        assert_eq!(auto_sized_signed_val!(-5).pow(2), 25);
+        assert_eq!(auto_sized_int_val!(-0x10).abs(), 16);
+        assert_eq!(
+            auto_sized_signed_val!(-170141183460469231731687303715884105728).count_ones(),
+            1
+        );
+        assert_eq!(auto_sized_int_val!(-128).checked_neg(), None);
+        const MIN: i128 = auto_sized_signed_val!(-170141183460469231731687303715884105728);
+        assert_eq!(MIN, i128::MIN);
+    }
+
+    #[test]
+    fn byte_arrays() {
+        assert_eq!(auto_sized_le_bytes!(0xAB), [0xAB]);
+        assert_eq!(auto_sized_le_bytes!(300), [0x2C, 0x01]);
+        assert_eq!(auto_sized_be_bytes!(300), [0x01, 0x2C]);
+        assert_eq!(
+            auto_sized_be_bytes!(0x0001, width_from_digits),
+            [0x00, 0x01]
+        );
+        let bytes: [u8; 4] = auto_sized_be_bytes!(0x1_0000);
+        assert_eq!(bytes, [0x00, 0x01, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn int_unsigned_range() {
+        assert_type_eq_all!(auto_sized_int!(18446744073709551616), u128);
+        assert_type_eq_all!(
+            auto_sized_int!(340282366920938463463374607431768211455),
+            u128
+        );
+        assert_type_eq_all!(auto_sized_int!(u128::MAX), u128);
+        assert_type_eq_all!(auto_sized_int!(u128::MAX - 1), u128);
+        assert_eq!(
+            auto_sized_int_val!(18446744073709551616),
+            18446744073709551616u128
+        );
+        assert_eq!(
+            auto_sized_int_val!(340282366920938463463374607431768211455),
+            u128::MAX
+        );
+        assert_type_eq_all!(
+            auto_sized_int!(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF),
+            u128
+        );
+        assert_type_eq_all!(
+            auto_sized_int!(170141183460469231731687303715884105728),
+            u128
+        );
+        assert_eq!(auto_sized_int_val!(u128::MAX), u128::MAX);
+        assert_eq!(
+            auto_sized_int_val!(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF),
+            u128::MAX
+        );
+        assert_eq!(auto_sized_int_val!(-200), -200i16);
+        assert_eq!(auto_sized_int_val!(10 - 300), -290i16);
+    }
+
+    #[test]
+    fn int_preference() {
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(200), i16);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(127), i8);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(-200), i16);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(200u8), u8);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(u128::MAX), u128);
+        assert_type_eq_all!(auto_sized_int_prefer_unsigned!(200), u8);
+        assert_type_eq_all!(auto_sized_int_prefer_unsigned!(-200), i16);
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_type_eq_all!(auto_sized_unsigned!(300,), u16);
+        assert_type_eq_all!(auto_sized_signed!(-200,), i16);
+        assert_type_eq_all!(auto_sized_int!(10,), u8);
+        assert_eq!(auto_sized_int_val!(-10,), -10i8);
+        assert_type_eq_all!(auto_sized_unsigned!(0x0F, width_from_digits,), u8);
+    }
+
+    #[test]
+    fn max_width() {
+        assert_type_eq_all!(auto_sized_unsigned!(300, max = u16), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(70000, max = u64), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(u64::MAX, max = u64), u64);
+        assert_type_eq_all!(
+            auto_sized_unsigned!(0x00FF, width_from_digits, max = u16),
+            u16
+        );
+        assert_eq!(auto_sized_unsigned_val!(255, max = u8), 255u8);
+    }
+
+    #[test]
+    fn min_width() {
+        assert_type_eq_all!(auto_sized_unsigned!(10, min = u16), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(70000, min = u16), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(10, min = u16, max = u32), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(10, min = u128), u128);
+        assert_eq!(auto_sized_unsigned_val!(10, min = u32), 10u32);
+    }
+
+    // The const fns in autosized-num-core share the macros' table.
+    #[test]
+    fn core_widths() {
+        use autosized_num_core::{smallest_signed_bits, smallest_unsigned_bits, width_for_bits};
+        use core::mem::size_of;
+
+        macro_rules! same_unsigned {
+            ($($value:expr),*) => {$(
+                assert_eq!(
+                    smallest_unsigned_bits($value) as usize,
+                    size_of::<auto_sized_unsigned!($value)>() * 8
+                );
+            )*};
+        }
+        macro_rules! same_signed {
+            ($($value:expr),*) => {$(
+                assert_eq!(
+                    smallest_signed_bits($value) as usize,
+                    size_of::<auto_sized_signed!($value)>() * 8
+                );
+            )*};
+        }
+
+        same_unsigned!(
+            0,
+            255,
+            256,
+            65_535,
+            65_536,
+            4_294_967_296,
+            18_446_744_073_709_551_615,
+            u128::MAX
+        );
+        same_signed!(
+            -128,
+            -129,
+            127,
+            128,
+            32_768,
+            -2_147_483_649,
+            -9_223_372_036_854_775_808,
+            i128::MIN
+        );
+        const WIDTH: u32 = smallest_unsigned_bits(70_000);
+        assert_eq!(WIDTH, 32);
+
+        macro_rules! same_bits {
+            ($($bits:literal),*) => {$(
+                assert_eq!(
+                    width_for_bits($bits),
+                    Some(size_of::<auto_sized_bits!($bits)>() as u32 * 8)
+                );
+            )*};
+        }
+        same_bits!(1, 8, 9, 33, 64, 65, 128);
+        assert_eq!(width_for_bits(129), None);
+    }
+
+    // One arm per pointer width, so building the tests for a 16-, 32-, or 64-bit
+    // target checks that target's choice.
+    #[test]
+    fn native_width() {
+        assert_type_eq_all!(auto_sized_unsigned!(255, native), usize);
+        assert_type_eq_all!(auto_sized_unsigned!(u128::MAX, native), u128);
+        assert_type_eq_all!(auto_sized_signed!(-200, native), isize);
+        assert_type_eq_all!(auto_sized_signed!(i128::MIN, native), i128);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_type_eq_all!(auto_sized_signed!(-10, native, max = i16), i8);
+            assert_type_eq_all!(auto_sized_signed!(-70_000, native, max = i32), i32);
+            assert_type_eq_all!(auto_sized_unsigned!(5_000_000_000, native), usize);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native), usize);
+            assert_type_eq_all!(auto_sized_signed!(-5_000_000_000, native), isize);
+            assert_eq!(
+                auto_sized_unsigned_val!(5_000_000_000, native),
+                5_000_000_000usize
+            );
+            assert_type_eq_all!(auto_sized_unsigned!(10, native, max = u16), u8);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native, max = u32), u32);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native, max = u64), usize);
+            assert_eq!(auto_sized_unsigned_val!(10, native, max = u16), 10u8);
+        }
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_type_eq_all!(auto_sized_signed!(-10, native, max = i16), i8);
+            assert_type_eq_all!(auto_sized_signed!(-70_000, native, max = i32), isize);
+            assert_type_eq_all!(auto_sized_unsigned!(5_000_000_000, native), u64);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native), usize);
+            assert_type_eq_all!(auto_sized_signed!(-5_000_000_000, native), i64);
+            assert_eq!(
+                auto_sized_unsigned_val!(5_000_000_000, native),
+                5_000_000_000u64
+            );
+            assert_type_eq_all!(auto_sized_unsigned!(10, native, max = u16), u8);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native, max = u32), usize);
+            assert_eq!(auto_sized_unsigned_val!(10, native, max = u16), 10u8);
+        }
+        #[cfg(target_pointer_width = "16")]
+        {
+            assert_type_eq_all!(auto_sized_signed!(-10, native, max = i16), isize);
+            assert_type_eq_all!(auto_sized_signed!(-70_000, native, max = i32), i32);
+            assert_type_eq_all!(auto_sized_unsigned!(5_000_000_000, native), u64);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native), u32);
+            assert_type_eq_all!(auto_sized_signed!(-5_000_000_000, native), i64);
+            assert_eq!(
+                auto_sized_unsigned_val!(5_000_000_000, native),
+                5_000_000_000u64
+            );
+            assert_type_eq_all!(auto_sized_unsigned!(10, native, max = u16), usize);
+            assert_type_eq_all!(auto_sized_unsigned!(70_000, native, max = u32), u32);
+            assert_eq!(auto_sized_unsigned_val!(10, native, max = u16), 10usize);
+        }
+
+        let items = [0u8; 300];
+        assert_eq!(items.len(), auto_sized_unsigned_val!(300, native));
+        assert_eq!(auto_sized_signed_val!(-200, native), -200isize);
+        const LEN: auto_sized_unsigned!(1_000, native) = auto_sized_unsigned_val!(1_000, native);
+        assert_eq!(LEN, 1_000);
+        assert_type_eq_all!(auto_sized_unsigned!(10, native, min = u16), usize);
+        assert_eq!(auto_sized_unsigned_max!(300, native), usize::MAX);
+        assert_eq!(auto_sized_unsigned_max!(u128::MAX, native), u128::MAX);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(auto_sized_unsigned_max!(10, native, max = u32), u8::MAX);
+    }
+
+    #[test]
+    fn type_hint() {
+        assert_type_eq_all!(auto_sized_unsigned!(300 => u16), u16);
+        assert_type_eq_all!(auto_sized_unsigned!(255 => u8), u8);
+        assert_type_eq_all!(auto_sized_unsigned!(u64::MAX => u64), u64);
+        assert_type_eq_all!(auto_sized_unsigned!(10 => u32, min = u32), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(0x00FF => u16, width_from_digits), u16);
+        assert_eq!(auto_sized_unsigned_val!(70_000 => u32), 70_000u32);
+        assert_eq!(auto_sized_unsigned_max!(300 => u16), u16::MAX);
+    }
+
+    #[test]
+    fn width_floors() {
+        assert_type_eq_all!(auto_sized_unsigned!(300, min = u32), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(5_000_000_000, min = u32), u64);
+        assert_type_eq_all!(auto_sized_unsigned!(300u64, min = u32), u64);
+        assert_type_eq_all!(auto_sized_unsigned!(300u16, min = u32), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(300, min = u32, max = u32), u32);
+        assert_type_eq_all!(auto_sized_signed!(-200, min = i32), i32);
+        assert_type_eq_all!(auto_sized_signed!(-3_000_000_000, min = i32), i64);
+        assert_type_eq_all!(auto_sized_signed!(5i64, min = i32), i64);
+        assert_type_eq_all!(auto_sized_signed!(5, min = i16, max = i32), i16);
+        assert_type_eq_all!(auto_sized_int!(300, min = u32), u32);
+        assert_type_eq_all!(auto_sized_int!(-200, min = u32), i32);
+        assert_type_eq_all!(auto_sized_int!(10, min = i64), u64);
+        assert_type_eq_all!(auto_sized_int!(10i64, min = i16), i64);
+        assert_type_eq_all!(auto_sized_int!(10, min = u32, ptr_width = 32), usize);
+        assert_eq!(auto_sized_unsigned_val!(300, min = u32), 300u32);
+        assert_eq!(auto_sized_signed_val!(-200, min = i64), -200i64);
+        assert_eq!(auto_sized_int_val!(-200, min = i32, max = i64), -200i32);
+        assert_eq!(auto_sized_int_val!(1_000, min = u128), 1_000u128);
+    }
+
+    #[test]
+    fn width_caps() {
+        assert_type_eq_all!(auto_sized_unsigned!(70_000, max = u32), u32);
+        assert_type_eq_all!(auto_sized_unsigned!(10, max = u16), u8);
+        assert_type_eq_all!(auto_sized_signed!(-200, max = i16), i16);
+        assert_type_eq_all!(auto_sized_signed!(i64::MIN, max = i64), i64);
+        assert_type_eq_all!(auto_sized_int!(300, max = i32), u16);
+        assert_type_eq_all!(auto_sized_int!(-70_000, max = u32), i32);
+        assert_type_eq_all!(auto_sized_int!(u64::MAX, max = u64), u64);
+        assert_eq!(auto_sized_unsigned_val!(70_000, max = u32), 70_000u32);
+        assert_eq!(auto_sized_signed_val!(-200, max = i16), -200i16);
+        assert_eq!(auto_sized_int_val!(-200, max = i64), -200i16);
+        assert_eq!(
+            auto_sized_int_val!(300, ptr_width = 16, max = u16),
+            300usize
+        );
+    }
+
+    #[test]
+    fn strlen() {
+        assert_type_eq_all!(auto_sized_strlen!("hello world"), u8);
+        assert_type_eq_all!(auto_sized_strlen!(""), u8);
+        assert_eq!(auto_sized_strlen_val!("hello world"), 11u8);
+        assert_eq!(auto_sized_strlen_val!("\u{1F600}"), 4u8);
+        assert_eq!(auto_sized_strlen_val!(r"\n"), 2u8);
+        assert_eq!(auto_sized_strlen_val!(r#"say "hi""#), 8u8);
+
+        // 256 bytes: the length needs u16, the indices 0..256 fit in u8.
+        assert_type_eq_all!(
+            auto_sized_strlen!(
+                "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+            ),
+            u16
+        );
+        assert_type_eq_all!(
+            auto_sized_strlen!(
+                "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\
+                 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+                exclusive
+            ),
+            u8
+        );
+    }
+
+    #[test]
+    fn bytelen() {
+        assert_type_eq_all!(auto_sized_bytelen!(b"\x7FELF"), u8);
+        assert_eq!(auto_sized_bytelen_val!(b"\x7FELF"), 4u8);
+        assert_eq!(auto_sized_bytelen_val!(br"\x7F"), 4u8);
+
+        static BLOB: &[u8] = include_bytes!("data/blob.bin");
+        assert_type_eq_all!(auto_sized_bytelen_file!("tests/data/blob.bin"), u16);
+        assert_eq!(auto_sized_bytelen_file_val!("tests/data/blob.bin"), 300u16);
+        assert_eq!(BLOB.len(), 300);
+    }
+
+    #[test]
+    fn char_code_point() {
+        assert_type_eq_all!(auto_sized_char!('A'), u8);
+        assert_type_eq_all!(auto_sized_char!('é'), u8);
+        assert_type_eq_all!(auto_sized_char!('€'), u16);
+        assert_type_eq_all!(auto_sized_char!('😀'), u32);
+        assert_eq!(auto_sized_char_val!('A'), 65u8);
+        assert_eq!(auto_sized_char_val!('\u{FFFF}'), 0xFFFFu16);
+        assert_eq!(auto_sized_char_val!('😀'), 0x1F600u32);
+    }
+
+    #[test]
+    fn index_type() {
+        assert_type_eq_all!(auto_index_type!([10, 20, 30, 40]), u8);
+        assert_type_eq_all!(auto_index_type!([]), u8);
+        assert_type_eq_all!(auto_index_type!([[1, 2, 3], [4, 5, 6]]), u8);
+        assert_type_eq_all!(auto_index_type!([f(1), "a".len(), 3]), u8);
+        assert_type_eq_all!(auto_index_type!([0u8; 256]), u8);
+        assert_type_eq_all!(auto_index_type!([0u8; 257]), u16);
+        assert_type_eq_all!(auto_index_type!([[0u8; 4]; 65536]), u16);
+        assert_type_eq_all!(auto_index_type!([0u8; 65537]), u32);
+    }
+
+    #[test]
+    fn flags() {
+        assert_type_eq_all!(auto_sized_flags!(5), u8);
+        assert_type_eq_all!(auto_sized_flags!(8), u8);
+        assert_type_eq_all!(auto_sized_flags!(9), u16);
+        assert_type_eq_all!(auto_sized_flags!(40), u64);
+        assert_type_eq_all!(auto_sized_flags!(128), u128);
+    }
+
+    #[test]
+    fn const_item() {
+        auto_sized_const!(LOCAL, 70000);
+
+        assert_eq!(super::MAX_FRAME, 300u16);
+        assert_eq!(super::OFFSET, -200i16);
+        assert_eq!(LOCAL, 70000u32);
+    }
+
+    #[test]
+    fn count() {
+        assert_type_eq_all!(auto_sized_count!(alpha beta gamma), u8);
+        assert_type_eq_all!(auto_sized_count!(a, b, c, d), u8);
+        assert_type_eq_all!(auto_sized_count!(), u8);
+        assert_type_eq_all!(counter_type!(a b c), u8);
+        assert_eq!(auto_sized_count_val!(alpha beta gamma), 3u8);
+        assert_eq!(auto_sized_count_val!(a, b, c, d,), 4u8);
+        assert_eq!(auto_sized_count_val!((a b) [c d] e), 3u8);
+        assert_eq!(auto_sized_count_val!(a + b, c), 2u8);
+        assert_eq!(auto_sized_count_val!(), 0u8);
+    }
+
+    #[test]
+    fn plus_unsigned_hint() {
+        assert_type_eq_all!(auto_sized_int!(+200), u8);
+        assert_type_eq_all!(auto_sized_int!(+0), u8);
+        assert_type_eq_all!(auto_sized_int!(+200u16), u16);
+        assert_eq!(auto_sized_int_val!(+200), 200u8);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(+200), u8);
+        assert_type_eq_all!(auto_sized_int_prefer_signed!(200), i16);
+        assert_type_eq_all!(auto_sized_int!(-200), i16);
+    }
+
+    #[test]
+    fn from_file() {
+        assert_type_eq_all!(auto_sized_from_file!("tests/data/max_packet.txt"), u16);
+        assert_type_eq_all!(auto_sized_from_file!("tests/data/offset.txt"), i8);
+        assert_type_eq_all!(
+            auto_sized_from_file!("tests/data/max_packet.txt", signed),
+            i16
+        );
+        assert_eq!(
+            auto_sized_from_file_val!("tests/data/max_packet.txt"),
+            1500u16
+        );
+        assert_eq!(auto_sized_from_file_val!("tests/data/offset.txt"), -128i8);
+
+        const MAX_PACKET: u16 = auto_sized_from_file_val!("tests/data/max_packet.txt");
+        assert_eq!(MAX_PACKET, 1500);
+    }
+
+    #[test]
+    fn fits() {
+        assert_fits!(255, u8);
+        assert_fits!(0, u8);
+        assert_fits!(127, i8);
+        assert_fits!(-128, i8);
+        assert_fits!(-129, i16);
+        assert_fits!(i128::MIN, i128);
+        assert_fits!(1 << 20, u32);
+    }
+
+    #[test]
+    fn common() {
+        assert_type_eq_all!(auto_sized_common!(3, 900, 70000), u32);
+        assert_type_eq_all!(auto_sized_common!(-1, 200), i16);
+        assert_type_eq_all!(auto_sized_common!(-1, 40000), i32);
+        assert_type_eq_all!(auto_sized_common!(-1, 127), i8);
+        assert_type_eq_all!(auto_sized_common!(300), u16);
+        assert_type_eq_all!(auto_sized_common!(-300), i16);
+        assert_type_eq_all!(auto_sized_common!(1, 2, 3,), u8);
+        assert_type_eq_all!(auto_sized_common!(u128::MAX, 0), u128);
+    }
+
+    #[test]
+    fn array() {
+        let table = auto_sized_array!([1, 2, 300, 40000]);
+        assert_eq!(table, [1u16, 2, 300, 40000]);
+        let offsets = auto_sized_array!([-1, 0, 100]);
+        assert_eq!(offsets, [-1i8, 0, 100]);
+        let wide = auto_sized_array!([-1, 70000,]);
+        assert_eq!(wide, [-1i32, 70000]);
+        let max = auto_sized_array!([0, u128::MAX]);
+        assert_eq!(max, [0u128, u128::MAX]);
+    }
+
+    #[test]
+    fn negative_radix() {
+        assert_type_eq_all!(auto_sized_signed!(-0x80), i8);
+        assert_type_eq_all!(auto_sized_signed!(-0x81), i16);
+        assert_type_eq_all!(auto_sized_signed!(-0x8000), i16);
+        assert_type_eq_all!(auto_sized_int!(-0x8000_0000), i32);
+        assert_type_eq_all!(auto_sized_int!(-0x8000_0000_0000_0000), i64);
+        assert_type_eq_all!(auto_sized_signed!(-0o200), i8);
+        assert_type_eq_all!(auto_sized_int!(-0b1000_0000_0000_0000), i16);
+        assert_eq!(auto_sized_signed_val!(-0x80), i8::MIN);
+        assert_eq!(auto_sized_int_val!(-0x8000), i16::MIN);
+        assert_eq!(auto_sized_signed_val!(-0x8000_0000), i32::MIN);
+        assert_eq!(auto_sized_int_val!(-0x8000_0000_0000_0000), i64::MIN);
+        assert_eq!(
+            auto_sized_signed_val!(-0x8000_0000_0000_0000_0000_0000_0000_0000),
+            i128::MIN
+        );
+    }
+
+    #[test]
+    fn int_ptr_width() {
+        assert_type_eq_all!(auto_sized_int!(70000, ptr_width = 32), usize);
+        assert_type_eq_all!(auto_sized_int!(-70000, ptr_width = 32), isize);
+        assert_type_eq_all!(auto_sized_int!(300, ptr_width = 16), usize);
+        assert_type_eq_all!(auto_sized_int!(300, ptr_width = 32), u16);
+        assert_type_eq_all!(auto_sized_int!(5_000_000_000, ptr_width = 32), u64);
+        assert_type_eq_all!(auto_sized_int!(5_000_000_000, ptr_width = 64), usize);
+        assert_type_eq_all!(auto_sized_int!(70000u32, ptr_width = 32), u32);
+        assert_type_eq_all!(auto_sized_int!(+70000, ptr_width = 32,), usize);
+        assert_eq!(auto_sized_int_val!(70000, ptr_width = 32), 70000usize);
+        assert_eq!(auto_sized_int_val!(-200, ptr_width = 16), -200isize);
+        assert_eq!(auto_sized_int_val!(10, ptr_width = 64), 10u8);
+    }
+
+    #[test]
+    fn radix() {
+        assert_type_eq_all!(auto_sized_radix!("1F4", 16), u16);
+        assert_type_eq_all!(auto_sized_radix!("1f4", 16), u16);
+        assert_type_eq_all!(auto_sized_radix!("777", 8), u16);
+        assert_type_eq_all!(auto_sized_radix!("1111_1111", 2), u8);
+        assert_type_eq_all!(auto_sized_radix!("-80", 16), i8);
+        assert_type_eq_all!(auto_sized_radix!("zz", 36), u16);
+        assert_eq!(auto_sized_radix_val!("1F4", 16), 500u16);
+        assert_eq!(auto_sized_radix_val!("-1_0000_0000", 2), -256i16);
+        assert_eq!(auto_sized_radix_val!("ZZ", 36,), 1295u16);
+    }
+
+    #[test]
+    fn saturating() {
+        use core::num::Saturating;
+
+        assert_type_eq_all!(auto_sized_saturating!(300), Saturating<u16>);
+        assert_type_eq_all!(auto_sized_saturating!(-200), Saturating<i16>);
+        assert_eq!(auto_sized_saturating_val!(300), Saturating(300u16));
+        assert_eq!(auto_sized_saturating_val!(-200), Saturating(-200i16));
+        assert_eq!(
+            auto_sized_saturating_val!(250) + Saturating(10),
+            Saturating(255u8)
+        );
+        assert_eq!(
+            auto_sized_saturating_val!(-100) - Saturating(100),
+            Saturating(-128i8)
+        );
+
+        assert_type_eq_all!(auto_sized_saturating_unsigned!(1_000_000), Saturating<u32>);
+        assert_type_eq_all!(
+            auto_sized_saturating_unsigned!(10, min = u16),
+            Saturating<u16>
+        );
+        assert_type_eq_all!(auto_sized_saturating_signed!(200), Saturating<i16>);
+        assert_type_eq_all!(
+            auto_sized_saturating_signed!(300),
+            Saturating<auto_sized_signed!(300)>
+        );
+
+        const TOTAL: auto_sized_saturating_unsigned!(1_000_000) =
+            auto_sized_saturating_unsigned_val!(1_000_000);
+        assert_eq!(TOTAL, Saturating(1_000_000u32));
+        assert_eq!(TOTAL + Saturating(u32::MAX), Saturating(u32::MAX));
+        static LEVEL: auto_sized_saturating_signed!(-100) = auto_sized_saturating_signed_val!(-100);
+        assert_eq!(LEVEL - Saturating(100), Saturating(i8::MIN));
+    }
+
+    #[test]
+    fn twos_complement() {
+        assert_eq!(auto_from_twos_complement!(0xFFF0, 16), -16i8);
+        assert_eq!(auto_from_twos_complement!(0xFFF0, 16, exact), -16i16);
+        assert_eq!(auto_from_twos_complement!(0x7FFF, 16), 32767i16);
+        assert_eq!(auto_from_twos_complement!(0x80, 8), i8::MIN);
+        assert_eq!(auto_from_twos_complement!(0x80, 16), 128i16);
+        assert_eq!(auto_from_twos_complement!(0xFFFF_FFFF, 32), -1i8);
+        assert_eq!(
+            auto_from_twos_complement!(0x8000_0000_0000_0000, 64),
+            i64::MIN
+        );
+        assert_eq!(auto_from_twos_complement!(u128::MAX, 128), -1i8);
+        assert_eq!(auto_from_twos_complement!(5, 128, exact,), 5i128);
+        assert_type_eq_all!(auto_from_twos_complement_type!(0xFFF0, 16), i8);
+        assert_type_eq_all!(auto_from_twos_complement_type!(0xFFF0, 16, exact), i16);
+        assert_type_eq_all!(auto_from_twos_complement_type!(0x8000, 16), i16);
+    }
+
+    #[test]
+    fn digits() {
+        assert_type_eq_all!(auto_sized_digits!(1), u8);
+        assert_type_eq_all!(auto_sized_digits!(2), u8);
+        assert_type_eq_all!(auto_sized_digits!(3), u16);
+        assert_type_eq_all!(auto_sized_digits!(7), u32);
+        assert_type_eq_all!(auto_sized_digits!(19), u64);
+        assert_type_eq_all!(auto_sized_digits!(20), u128);
+        assert_type_eq_all!(auto_sized_digits!(38), u128);
+        assert_type_eq_all!(auto_sized_digits!(2, signed), i8);
+        assert_type_eq_all!(auto_sized_digits!(3, signed), i16);
+        assert_type_eq_all!(auto_sized_digits!(18, signed), i64);
+        assert_type_eq_all!(auto_sized_digits!(19, signed,), i128);
+    }
+
+    #[test]
+    fn shadowed_primitives() {
+        #[allow(non_camel_case_types)]
+        type u16 = &'static str;
+        #[allow(non_camel_case_types)]
+        type i8 = ();
+
+        assert_type_eq_all!(auto_sized_unsigned!(300), ::core::primitive::u16);
+        assert_type_eq_all!(auto_sized_int!(-1), ::core::primitive::i8);
+        assert_type_eq_all!(auto_sized_range!(-40, 125), ::core::primitive::i8);
+        let x: auto_sized_unsigned!(300) = auto_sized_unsigned_val!(300);
+        let label: u16 = "unrelated";
+        let unit: i8 = ();
+        assert_eq!(x, 300);
+        assert_eq!(label, "unrelated");
+        assert_eq!(unit, ());
+    }
+
+    #[test]
+    fn type_bounds() {
+        assert_eq!(auto_sized_unsigned_max!(255), u8::MAX);
+        assert_eq!(auto_sized_unsigned_max!(300), u16::MAX);
+        assert_eq!(auto_sized_unsigned_max!(300, min = u32), u32::MAX);
+        assert_eq!(auto_sized_unsigned_max!(u64::MAX), u64::MAX);
+        let incoming: u32 = 70_000;
+        assert!(incoming > auto_sized_unsigned_max!(300).into());
+
+        assert_eq!(auto_sized_signed_min!(-200), i16::MIN);
+        assert_eq!(auto_sized_signed_max!(-200), i16::MAX);
+        assert_eq!(auto_sized_signed_min!(100), i8::MIN);
+        assert_eq!(auto_sized_signed_max!(i128::MIN), i128::MAX);
+        const RANGE: (i16, i16) = (auto_sized_signed_min!(300), auto_sized_signed_max!(300));
+        assert_eq!(RANGE, (i16::MIN, i16::MAX));
+    }
+
+    #[test]
+    fn for_bits() {
+        assert_type_eq_all!(auto_sized_for_bits!(1), u8);
+        assert_type_eq_all!(auto_sized_for_bits!(8), u8);
+        assert_type_eq_all!(auto_sized_for_bits!(24), u32);
+        assert_type_eq_all!(auto_sized_for_bits!(64), u64);
+        assert_type_eq_all!(auto_sized_for_bits!(128), u128);
+        assert_type_eq_all!(auto_sized_for_bits!(24), auto_sized_bits!(24));
+
+        assert_type_eq_all!(auto_sized_for_bits_signed!(1), i8);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(7), i8);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(8), i16);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(24), i32);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(63), i64);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(64), i128);
+        assert_type_eq_all!(auto_sized_for_bits_signed!(127), i128);
+    }
+
+    #[test]
+    fn hex_digits() {
+        assert_type_eq_all!(auto_sized_hex_digits!(1), u8);
+        assert_type_eq_all!(auto_sized_hex_digits!(2), u8);
+        assert_type_eq_all!(auto_sized_hex_digits!(3), u16);
+        assert_type_eq_all!(auto_sized_hex_digits!(8), u32);
+        assert_type_eq_all!(auto_sized_hex_digits!(16), u64);
+        assert_type_eq_all!(auto_sized_hex_digits!(32), u128);
+        assert_type_eq_all!(auto_sized_hex_digits!(2), auto_sized_flags!(8));
+        assert_eq!(auto_sized_hex_digits_val!(8), 0xFFFF_FFFFu32);
+        assert_eq!(auto_sized_hex_digits_val!(3), 0xFFFu16);
+        assert_eq!(auto_sized_hex_digits_val!(32), u128::MAX);
+        assert_eq!(auto_sized_hex_digits_val!(1), 0xFu8);
+    }
+
+    #[test]
+    fn boundary_literals() {
+        assert_type_eq_all!(auto_sized_unsigned!(u8::MAX + 1), u16);
+        assert_eq!(auto_sized_unsigned_val!(u8::MAX + 1), 256u16);
+        assert_eq!(auto_sized_unsigned_val!(u8::MAX as u128 + 1), 256u16);
+        assert_eq!(auto_sized_unsigned_val!(u16::MAX as u128 + 1), 65536u32);
+        assert_eq!(auto_sized_unsigned_val!(u32::MAX as u64 + 1), 4294967296u64);
+        assert_eq!(
+            auto_sized_unsigned_val!(u64::MAX as u128 + 1),
+            18446744073709551616u128
+        );
+        assert_eq!(auto_sized_signed_val!(i8::MIN as i16 - 1), -129i16);
+        assert_eq!(auto_sized_signed_val!(-(i16::MAX as i32) - 2), -32769i32);
+        assert_eq!(auto_sized_int_val!(u8::MAX as i16), 255u8);
+        assert_eq!(auto_sized_int_val!(i8::MIN as i64), -128i8);
+    }
+
+    #[test]
+    fn bits() {
+        assert_type_eq_all!(auto_sized_bits!(1), u8);
+        assert_type_eq_all!(auto_sized_bits!(8), u8);
+        assert_type_eq_all!(auto_sized_bits!(13), u16);
+        assert_type_eq_all!(auto_sized_bits!(33), u64);
+        assert_type_eq_all!(auto_sized_bits!(64), u64);
+        assert_type_eq_all!(auto_sized_bits!(128), u128);
+        assert_type_eq_all!(auto_sized_bits!(8), auto_sized_hex_digits!(2));
+        assert_eq!(auto_sized_bits_val!(13), 8191u16);
+        assert_eq!(auto_sized_bits_val!(1), 1u8);
+        assert_eq!(auto_sized_bits_val!(32), u32::MAX);
+        assert_eq!(auto_sized_bits_val!(128), u128::MAX);
+    }
+
+    #[test]
+    fn unsigned_in() {
+        assert_type_eq_all!(auto_sized_unsigned_in!([u16, u32, u64], 10), u16);
+        assert_type_eq_all!(auto_sized_unsigned_in!([u16, u32, u64], 70000), u32);
+        assert_type_eq_all!(auto_sized_unsigned_in!([u64, u16], 70000), u64);
+        assert_type_eq_all!(auto_sized_unsigned_in!([u8, u128,], 300), u128);
+        assert_type_eq_all!(auto_sized_unsigned_in!([u32], 1 << 20), u32);
+        assert_type_eq_all!(auto_sized_unsigned_in!([u8, u16, u32], 10u16), u16);
+    }
+
+    #[test]
+    fn bytes() {
+        assert_type_eq_all!(auto_sized_bytes!(1), u8);
+        assert_type_eq_all!(auto_sized_bytes!(3), u32);
+        assert_type_eq_all!(auto_sized_bytes!(8), u64);
+        assert_type_eq_all!(auto_sized_bytes!(9), u128);
+        assert_type_eq_all!(auto_sized_bytes!(16), u128);
+        assert_type_eq_all!(auto_sized_bytes!(2, exact), u16);
+        assert_type_eq_all!(auto_sized_bytes!(16, exact,), u128);
+        assert_type_eq_all!(auto_sized_bytes!(3), auto_sized_bits!(24));
+        assert_eq!(auto_sized_bytes_val!(3), 0xFF_FFFFu32);
+        assert_eq!(auto_sized_bytes_val!(2), u16::MAX);
+        assert_eq!(auto_sized_bytes_val!(16), u128::MAX);
+        assert_eq!(auto_sized_bytes_val!(8, exact), u64::MAX);
+    }
+
+    #[test]
+    fn const_and_static_types() {
+        assert_eq!(TIMEOUT_MS, 5000u16);
+        assert_eq!(DRIFT, -300i16);
+        assert_eq!(RETRIES, 300u16);
+        const fn tick() -> auto_sized_unsigned!(70000) {
+            const STEP: auto_sized_unsigned!(1000) = 1000;
+            STEP as u32
+        }
+        assert_eq!(tick(), 1000u32);
+    }
+
+    #[test]
+    fn len() {
+        assert_type_eq_all!(auto_sized_len!(256), u8);
+        assert_type_eq_all!(auto_sized_len!(257), u16);
+        assert_type_eq_all!(auto_sized_len!(0), u8);
+        assert_type_eq_all!(auto_sized_len!(1), u8);
+        assert_type_eq_all!(auto_sized_len!(65536), u16);
+        assert_type_eq_all!(auto_sized_len!(65537), u32);
+        assert_type_eq_all!(auto_sized_len!(u64::MAX as u128 + 1), u64);
+
+        assert_eq!(auto_sized_len_val!(200), 200u8);
+        assert_eq!(auto_sized_len_val!(0), 0u8);
+        assert_eq!(auto_sized_len_val!(257), 257u16);
+
+        const CAPACITY: auto_sized_len!(255) = auto_sized_len_val!(255);
+        let slot: auto_sized_len!(255) = 254;
+        assert!(slot < CAPACITY);
+    }
+
+    #[test]
+    fn source_digits() {
+        assert_eq!(auto_sized_unsigned_val!(1_000_000), 1_000_000u32);
+        assert_eq!(auto_sized_unsigned_val!(0xFF_FF), 65535u16);
+        assert_eq!(auto_sized_unsigned_val!(0b1111_1111), 255u8);
+        assert_eq!(auto_sized_unsigned_val!(10u32), 10u32);
+        assert_eq!(auto_sized_signed_val!(-0x80), -128i8);
+        assert_eq!(auto_sized_signed_val!(-(1_000)), -1000i16);
+        assert_eq!(auto_sized_int_val!(-9_223_372_036_854_775_808), i64::MIN);
+        assert_eq!(auto_sized_int_val!(0o777), 511u16);
+        assert_eq!(
+            auto_sized_int_val!(5_000_000_000, ptr_width = 64),
+            5_000_000_000usize
+        );
+        assert_eq!(auto_sized_array!([1_000, -0x10]), [1000i16, -16]);
+        assert_eq!(auto_sized_len_val!(1_000), 1000u16);
+        assert_eq!(auto_sized_unsigned_val!(u8::MAX), 255u8);
+        assert_eq!(auto_from_twos_complement!(0xFFF0, 16), -16i8);
+    }
+
+    #[test]
+    fn option_env() {
+        assert_type_eq_all!(auto_sized_option_env!("AUTOSIZED_TEST_LIMIT", 10), u16);
+        assert_type_eq_all!(
+            auto_sized_option_env!("AUTOSIZED_NUM_UNSET_VARIABLE", 1024),
+            u16
+        );
+        assert_type_eq_all!(
+            auto_sized_option_env!("AUTOSIZED_NUM_UNSET_VARIABLE", -5, signed),
+            i8
+        );
+        assert_type_eq_all!(auto_sized_option_env!("AUTOSIZED_TEST_OFFSET", 0, int), i16);
+        assert_eq!(
+            auto_sized_option_env_val!("AUTOSIZED_TEST_LIMIT", 10),
+            2000u16
+        );
+        assert_eq!(
+            auto_sized_option_env_val!("AUTOSIZED_NUM_UNSET_VARIABLE", 1 << 10),
+            1024u16
+        );
+        const CAPACITY: u8 = auto_sized_option_env_val!("AUTOSIZED_NUM_UNSET_VARIABLE", 200);
+        assert_eq!(CAPACITY, 200);
+    }
+
+    #[test]
+    fn cfg_arms() {
+        assert_eq!(auto_sized_cfg! { cfg(any()) => 512, _ => 65536 }, 65536u32);
+        assert_eq!(auto_sized_cfg! { cfg(all()) => 512, _ => 65536 }, 512u16);
+        assert_eq!(
+            auto_sized_cfg! { cfg(test) => 300, cfg(all()) => 10 },
+            300u16
+        );
+        assert_eq!(
+            auto_sized_cfg! { cfg(any()) => 300, cfg(test) => 10, },
+            10u8
+        );
+        assert_eq!(auto_sized_cfg! { _ => -200 }, -200i16);
+        assert_eq!(
+            auto_sized_cfg! { cfg(not(test)) => u64::MAX, _ => 1 << 20 },
+            1_048_576u32
+        );
+
+        assert_eq!(BUFFER_SIZE, 65536u32);
+        assert_eq!(SKEW, -5i8);
+        const PORT: u16 = auto_sized_cfg! { cfg(target_pointer_width = "16") => 80, _ => 8080 };
+        assert_eq!(PORT, 8080);
+    }
+
+    #[test]
+    fn pkg_version() {
+        let component = |text: &str| text.parse::<u32>().unwrap();
+        let major = component(env!("CARGO_PKG_VERSION_MAJOR"));
+        let minor = component(env!("CARGO_PKG_VERSION_MINOR"));
+        let patch = component(env!("CARGO_PKG_VERSION_PATCH"));
+
+        assert_type_eq_all!(auto_sized_pkg_version!(major), u8);
+        assert_type_eq_all!(auto_sized_pkg_version!(packed, 8, 8, 16), u32);
+        assert_type_eq_all!(auto_sized_pkg_version!(packed, 64, 32, 32), u128);
+        assert_eq!(u32::from(auto_sized_pkg_version_val!(major)), major);
+        assert_eq!(u32::from(auto_sized_pkg_version_val!(minor)), minor);
+        assert_eq!(u32::from(auto_sized_pkg_version_val!(patch)), patch);
+        assert_eq!(
+            auto_sized_pkg_version_val!(packed, 8, 8, 16),
+            major << 24 | minor << 16 | patch
+        );
+    }
+
+    #[test]
+    fn type_aliases() {
+        assert_type_eq_all!(SampleT, u32);
+        assert_type_eq_all!(Channel, u8);
+        assert_type_eq_all!(Delta, i16);
+        assert_type_eq_all!(Skew, i8);
+
+        auto_sized_type_alias!(Local, 1 << 16);
+        auto_sized_signed_type_alias!(Wide, i64::MIN);
+        assert_type_eq_all!(Local, u32);
+        assert_type_eq_all!(Wide, i64);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() {
+        type Payload = auto_sized_from_toml!("tests/data/limits.toml", "network.max_payload");
+        assert_type_eq_all!(Payload, u16);
+        assert_type_eq_all!(
+            auto_sized_from_toml!("tests/data/limits.toml", "network.skew"),
+            i16
+        );
+        assert_type_eq_all!(
+            auto_sized_from_toml!("tests/data/limits.toml", "network.retries", signed),
+            i8
+        );
+        assert_type_eq_all!(
+            auto_sized_from_toml!("tests/data/limits.toml", "limits.huge"),
+            u64
+        );
+        assert_eq!(
+            auto_sized_from_toml_val!("tests/data/limits.toml", "network.max_payload"),
+            1500u16
+        );
+        assert_eq!(
+            auto_sized_from_toml_val!("tests/data/limits.toml", "network.retries"),
+            3u8
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json() {
+        assert_type_eq_all!(
+            auto_sized_from_json!("tests/data/limits.json", "network.max_payload"),
+            u16
+        );
+        assert_type_eq_all!(
+            auto_sized_from_json!("tests/data/limits.json", "network.skew"),
+            i16
+        );
+        assert_type_eq_all!(
+            auto_sized_from_json!("tests/data/limits.json", "limits.huge"),
+            u64
+        );
+        assert_eq!(
+            auto_sized_from_json_val!("tests/data/limits.json", "network.retries", unsigned),
+            3u8
+        );
+        assert_eq!(
+            auto_sized_from_json_val!("tests/data/limits.json", "network.skew"),
+            -200i16
+        );
+    }
+
+    #[test]
+    fn newtype() {
+        let SensorId(raw) = SensorId(299);
+        let Drift(delta) = Drift(-199);
+        let _: (u16, i16) = (raw, delta);
+        assert_eq!(core::mem::size_of::<SensorId>(), 2);
+    }
+
+    #[test]
+    fn struct_fields() {
+        let packet = Packet {
+            a: 255,
+            b: 70000,
+            skew: -200,
+            len: 0,
+            tag: "ping",
+        };
+        let Packet {
+            a, b, skew, tag, ..
+        } = packet;
+        let _: (u8, u32, i16, &str) = (a, b, skew, tag);
+        assert_eq!(Packet::default().a, 0u8);
+
+        let tagged = Tagged {
+            id: 300,
+            payload: 1.5f32,
+        };
+        let _: (u16, f32) = (tagged.id, tagged.payload);
+        assert_eq!(core::mem::size_of::<Tagged<u8>>(), 4);
+    }
+
+    #[test]
+    fn newtype_serde() {
+        static_assertions::assert_impl_all!(SerdeId: serde::Serialize, serde::de::DeserializeOwned);
+        static_assertions::assert_impl_all!(SerdeDrift: serde::Serialize, serde::de::DeserializeOwned);
+        static_assertions::assert_not_impl_any!(SensorId: serde::Serialize);
+        let SerdeId(raw) = SerdeId(299);
+        let _: u16 = raw;
+    }
 }
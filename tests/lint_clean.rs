@@ -0,0 +1,52 @@
+//! Uses the value macros under denied clippy lints, so an expansion that needs
+//! an `allow` at the call site fails `cargo clippy --all-targets` here.
+#![deny(clippy::all, clippy::cast_lossless, clippy::unnecessary_cast)]
+
+use autosized_num::*;
+use core::num::{NonZeroU16, Saturating, Wrapping};
+use core::sync::atomic::{AtomicU16, Ordering};
+
+const LIMIT: u16 = auto_sized_unsigned_val!(50_000);
+const OFFSET: i16 = auto_sized_signed_val!(-200);
+static COUNTER: AtomicU16 = auto_sized_atomic_val!(300);
+
+#[test]
+fn widened_values() {
+    let wide = u32::from(auto_sized_unsigned_val!(300)) + u32::from(LIMIT);
+    assert_eq!(wide, 50_300);
+    let signed = i64::from(auto_sized_int_val!(-0x10)) * i64::from(OFFSET);
+    assert_eq!(signed, 3200);
+    assert_eq!(f64::from(auto_sized_float_val!(1.5)), 1.5);
+    assert_eq!(
+        u128::from(auto_sized_int_val!(u64::MAX)),
+        u128::from(u64::MAX)
+    );
+}
+
+#[test]
+fn typed_values() {
+    assert_eq!(auto_sized_bits_val!(13), 8191);
+    assert_eq!(auto_sized_bytes_val!(2), u16::MAX);
+    assert_eq!(auto_sized_hex_digits_val!(3), 0xFFF);
+    assert_eq!(auto_sized_or_val!(0x01, 0x80), 0x81);
+    assert_eq!(auto_sized_count_val!(a, b, c), 3);
+    assert_eq!(auto_sized_len_val!(300), 300);
+    assert_eq!(auto_sized_char_val!('A'), 65);
+    assert_eq!(auto_sized_strlen_val!("hello"), 5);
+    assert_eq!(auto_sized_bytelen_val!(b"\x7FELF"), 4);
+    assert_eq!(auto_sized_radix_val!("1F4", 16), 500);
+    assert_eq!(auto_sized_env_val!("AUTOSIZED_TEST_LIMIT"), 2000);
+    assert_eq!(auto_sized_option_env_val!("AUTOSIZED_TEST_UNSET", 10), 10);
+}
+
+#[test]
+fn wrapped_values() {
+    let id: NonZeroU16 = auto_sized_nonzero_val!(300);
+    assert_eq!(id.get(), 300);
+    assert_eq!(auto_sized_wrapping_val!(255) + Wrapping(1), Wrapping(0));
+    assert_eq!(
+        auto_sized_saturating_unsigned_val!(250) + Saturating(10),
+        Saturating(255)
+    );
+    assert_eq!(COUNTER.fetch_add(1, Ordering::Relaxed), 300);
+}
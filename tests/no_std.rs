@@ -0,0 +1,144 @@
+//! Uses every macro from a `#![no_std]` crate, so an expansion that reaches for
+//! `std` fails to build here.
+#![no_std]
+
+use autosized_num::*;
+use core::num::{NonZeroI16, NonZeroU16, Saturating, Wrapping};
+use core::sync::atomic::{AtomicI16, AtomicI32, AtomicU16, Ordering};
+use static_assertions::assert_type_eq_all;
+
+auto_sized_const!(LIMIT, 300);
+auto_sized_type_alias!(Sample, 70000);
+auto_sized_signed_type_alias!(Offset, -200);
+auto_sized_newtype!(Id, 300);
+auto_sized_struct! {
+    struct Frame {
+        len: 1500,
+        checksum: u32,
+    }
+}
+auto_sized_cfg! { BUFFER_SIZE, cfg(target_os = "none") => 512, _ => 65536 }
+assert_fits!(300, u16);
+
+#[auto_size_consts]
+mod consts {
+    pub const OFFSET: _ = -200;
+}
+
+#[derive(Clone, Copy, AutoRepr)]
+enum Level {
+    Low = -1,
+    High = 200,
+}
+
+#[test]
+fn type_macros() {
+    assert_type_eq_all!(auto_sized_unsigned!(300), u16);
+    assert_type_eq_all!(auto_sized_signed!(-200), i16);
+    assert_type_eq_all!(auto_sized_int!(70000, ptr_width = 32), usize);
+    assert_type_eq_all!(auto_sized_int_prefer_signed!(200), i16);
+    assert_type_eq_all!(auto_sized_int_prefer_unsigned!(-1), i8);
+    assert_type_eq_all!(auto_sized_float!(1.5), f32);
+    assert_type_eq_all!(auto_sized_unsigned_all!(10, 300), u16);
+    assert_type_eq_all!(auto_sized_signed_all!(-200, 100), i16);
+    assert_type_eq_all!(auto_sized_common!(-1, 200), i16);
+    assert_type_eq_all!(auto_sized_range!(-40, 125), i8);
+    assert_type_eq_all!(auto_sized_nonzero_unsigned!(300), NonZeroU16);
+    assert_type_eq_all!(auto_sized_nonzero!(300), NonZeroU16);
+    assert_type_eq_all!(auto_sized_nonzero_signed!(-200), NonZeroI16);
+    assert_type_eq_all!(auto_sized_wrapping!(300), Wrapping<u16>);
+    assert_type_eq_all!(auto_sized_saturating!(300), Saturating<u16>);
+    assert_type_eq_all!(auto_sized_saturating_unsigned!(300), Saturating<u16>);
+    assert_type_eq_all!(auto_sized_saturating_signed!(300), Saturating<i16>);
+    assert_type_eq_all!(auto_sized_atomic!(300), AtomicU16);
+    assert_type_eq_all!(auto_sized_atomic_signed!(300, min = 32), AtomicI32);
+    assert_type_eq_all!(auto_from_twos_complement_type!(0xFFF0, 16), i8);
+    assert_type_eq_all!(auto_sized_or!(0x01, 0x80), u8);
+    assert_type_eq_all!(auto_sized_usize!(300), usize);
+    assert_type_eq_all!(auto_sized_isize!(-300), isize);
+    assert_type_eq_all!(auto_sized_flags!(9), u16);
+    assert_type_eq_all!(auto_sized_bits!(13), u16);
+    assert_eq!(auto_sized_unsigned_max!(300), u16::MAX);
+    assert_eq!(auto_sized_signed_min!(-200), i16::MIN);
+    assert_type_eq_all!(auto_sized_for_bits!(24), u32);
+    assert_type_eq_all!(auto_sized_for_bits_signed!(24), i32);
+    assert_type_eq_all!(auto_sized_bytes!(3), u32);
+    assert_type_eq_all!(auto_sized_digits!(7), u32);
+    assert_type_eq_all!(auto_sized_count!(a, b, c), u8);
+    assert_type_eq_all!(auto_index_type!([0u8; 257]), u16);
+    assert_type_eq_all!(auto_sized_len!(256), u8);
+    assert_type_eq_all!(auto_sized_char!('€'), u16);
+    assert_type_eq_all!(auto_sized_strlen!("hello"), u8);
+    assert_type_eq_all!(auto_sized_bytelen!(b"\x7FELF"), u8);
+    assert_type_eq_all!(auto_sized_bytelen_file!("tests/data/blob.bin"), u16);
+    assert_type_eq_all!(auto_sized_env!("AUTOSIZED_TEST_LIMIT"), u16);
+    assert_type_eq_all!(auto_sized_option_env!("AUTOSIZED_TEST_LIMIT", 10), u16);
+    assert_type_eq_all!(auto_sized_from_file!("tests/data/max_packet.txt"), u16);
+    assert_type_eq_all!(auto_sized_radix!("1F4", 16), u16);
+}
+
+#[test]
+fn value_macros() {
+    assert_eq!(auto_sized_unsigned_val!(300), 300u16);
+    assert_eq!(auto_sized_signed_val!(-200), -200i16);
+    assert_eq!(auto_sized_int_val!(10), 10u8);
+    assert_eq!(auto_sized_float_val!(1.5), 1.5f32);
+    assert_eq!(auto_sized_array!([-1, 0, 100]), [-1i8, 0, 100]);
+    assert_eq!(auto_sized_bit_width!(300), 16);
+    assert_eq!(auto_sized_bits_val!(13), 8191u16);
+    assert_eq!(auto_sized_byte_width!(300), 2);
+    assert_eq!(auto_sized_bytes_val!(3), 0xFF_FFFFu32);
+    assert_eq!(auto_sized_len_val!(300), 300u16);
+    assert_eq!(
+        auto_sized_nonzero_unsigned_val!(300),
+        NonZeroU16::new(300).unwrap()
+    );
+    assert_eq!(
+        auto_sized_nonzero_signed_val!(-200),
+        NonZeroI16::new(-200).unwrap()
+    );
+    assert_eq!(auto_sized_wrapping_val!(300), Wrapping(300u16));
+    assert_eq!(auto_sized_wrapping_signed_val!(200), Wrapping(200i16));
+    assert_type_eq_all!(auto_sized_wrapping_unsigned!(300), Wrapping<u16>);
+    assert_eq!(auto_sized_saturating_val!(300), Saturating(300u16));
+    let atomic: AtomicI16 = auto_sized_atomic_val!(-200);
+    assert_eq!(atomic.load(Ordering::Relaxed), -200);
+    assert_eq!(auto_sized_le_bytes!(300), [0x2C, 0x01]);
+    assert_eq!(auto_sized_be_bytes!(300), [0x01, 0x2C]);
+    assert_eq!(auto_from_twos_complement!(0xFFF0, 16), -16i8);
+    assert_eq!(auto_sized_or_val!(0x01, 0x80), 0x81u8);
+    assert_eq!(auto_sized_count_val!(a, b, c), 3u8);
+    assert_eq!(auto_sized_char_val!('A'), 65u8);
+    assert_eq!(auto_sized_strlen_val!("hello"), 5u8);
+    assert_eq!(auto_sized_bytelen_val!(b"\x7FELF"), 4u8);
+    assert_eq!(auto_sized_bytelen_file_val!("tests/data/blob.bin"), 300u16);
+    assert_eq!(auto_sized_env_val!("AUTOSIZED_TEST_LIMIT"), 2000u16);
+    assert_eq!(
+        auto_sized_option_env_val!("AUTOSIZED_TEST_LIMIT", 10),
+        2000u16
+    );
+    assert_eq!(
+        auto_sized_from_file_val!("tests/data/max_packet.txt"),
+        1500u16
+    );
+    assert_eq!(auto_sized_radix_val!("1F4", 16), 500u16);
+}
+
+#[test]
+fn item_macros() {
+    assert_eq!(LIMIT, 300u16);
+    assert_eq!(BUFFER_SIZE, 65536u32);
+    assert_type_eq_all!(Sample, u32);
+    assert_type_eq_all!(Offset, i16);
+    assert_eq!(core::mem::size_of::<Id>(), 2);
+    assert_eq!(core::mem::size_of::<Frame>(), 8);
+    let Frame { len, checksum } = Frame {
+        len: 1500,
+        checksum: 0,
+    };
+    let _: (u16, u32) = (len, checksum);
+    assert_type_eq_all!(auto_sized_pkg_version!(packed, 8, 8, 16), u32);
+    assert_eq!(consts::OFFSET, -200i16);
+    assert_eq!(i16::from(Level::Low), -1);
+    assert_eq!(i16::from(Level::High), 200);
+}
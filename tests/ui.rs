@@ -0,0 +1,12 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[cfg(all(feature = "toml", feature = "json"))]
+#[test]
+fn ui_config() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui_config/*.rs");
+}
@@ -0,0 +1,10 @@
+use autosized_num::*;
+
+assert_fits!(300, u8);
+assert_fits!(-1, u64);
+assert_fits!(128, i8);
+assert_fits!(-129, i8);
+assert_fits!(170141183460469231731687303715884105728, i128);
+assert_fits!(5, usize);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use autosized_num::*;
+
+type A = auto_sized_atomic!(18446744073709551616);
+type B = auto_sized_atomic_signed!(-9223372036854775809);
+type C = auto_sized_atomic!(300, min = 128);
+type D = auto_sized_atomic!(300, min = u32);
+type E = auto_sized_atomic_signed!(300, max = 32);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use autosized_num::*;
+
+auto_sized_cfg! { NONE, cfg(any()) => 1 }
+
+fn main() {
+    let a = auto_sized_cfg! { cfg(any()) => 512 };
+    let b = auto_sized_cfg! { _ => 1, cfg(all()) => 2 };
+    let c = auto_sized_cfg! { feature(x) => 1 };
+    let d = auto_sized_cfg! { cfg(any()) => 1.5, _ => 2 };
+    let e = auto_sized_cfg! {};
+}
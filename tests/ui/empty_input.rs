@@ -0,0 +1,11 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!();
+type B = auto_sized_signed!();
+type C = auto_sized_int!();
+
+fn main() {
+    let _ = auto_sized_unsigned_val!();
+    let _ = auto_sized_signed_val!();
+    let _ = auto_sized_int_val!();
+}
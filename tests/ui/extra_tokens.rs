@@ -0,0 +1,9 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(300, 400);
+type B = auto_sized_int!(10;);
+type C = auto_sized_signed!();
+type D = auto_sized_unsigned!(0x0F, width_from_digits, 5);
+type E = auto_sized_usize!(300 400);
+
+fn main() {}
@@ -0,0 +1,14 @@
+use autosized_num::*;
+
+type A = auto_sized_float!(9_007_199_254_740_993);
+type B = auto_sized_float!(-9_007_199_254_740_993);
+type C = auto_sized_float!(u128::MAX);
+type D = auto_sized_float!(0.1f32);
+type E = auto_sized_float!(-(1i32 << 20));
+
+fn main() {
+    let _ = auto_sized_float_val!(0x20_0000_0000_0001);
+    let _ = auto_sized_float_val!(-9_007_199_254_740_993);
+    let _ = auto_sized_float_val!(1e39f32);
+    let _ = auto_sized_float_val!(16_777_216u64);
+}
@@ -0,0 +1,7 @@
+use autosized_num::*;
+
+fn main() {
+    let a = auto_sized_len_val!(256);
+    let b = auto_sized_len_val!(65536);
+    let c = auto_sized_len_val!(-1);
+}
@@ -0,0 +1,14 @@
+use autosized_num::*;
+
+const LIMIT: u32 = 300;
+
+mod config {
+    pub const OFFSET: i32 = -200;
+}
+
+type A = auto_sized_unsigned!(LIMIT);
+type B = auto_sized_signed!(config::OFFSET);
+type C = auto_sized_int!(LIMIT * 2);
+type D = auto_sized_unsigned!(u8::BITS);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use autosized_num::*;
+
+type A = auto_sized_wrapping_unsigned!(300, native);
+type B = auto_sized_signed!(300, nativ);
+type C = auto_sized_unsigned!(300, native, max = u8);
+type D = auto_sized_unsigned!(10 => u8, native);
+
+fn vals() {
+    let _ = auto_sized_unsigned_val!(300 => u16, native);
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(-5);
+type B = auto_sized_unsigned!(10 + -5);
+
+fn main() {
+    let _ = auto_sized_unsigned_val!(-(2 * 150));
+}
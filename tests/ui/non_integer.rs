@@ -0,0 +1,11 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!("300");
+type B = auto_sized_signed!(true);
+type C = auto_sized_int!([1, 2]);
+
+fn main() {
+    let _ = auto_sized_unsigned_val!(x.y);
+    let _ = auto_sized_signed_val!("-200");
+    let _ = auto_sized_int_val!(f(10));
+}
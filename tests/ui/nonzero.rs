@@ -0,0 +1,9 @@
+use autosized_num::*;
+
+type A = auto_sized_nonzero!(0);
+type B = auto_sized_nonzero!(-5);
+type C = auto_sized_nonzero!(1 - 1);
+type D = auto_sized_nonzero_unsigned!(-(2 * 100));
+type E = auto_sized_nonzero_signed!(5 - 5);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use autosized_num::*;
+
+type A = auto_sized_option_env!("AUTOSIZED_TEST_LIMIT", -1);
+type B = auto_sized_option_env!("AUTOSIZED_NUM_UNSET_VARIABLE", -1);
+type C = auto_sized_option_env!("AUTOSIZED_TEST_OFFSET", 10);
+type D = auto_sized_option_env!("AUTOSIZED_TEST_LIMIT", 10, wide);
+
+fn main() {}
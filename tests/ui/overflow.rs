@@ -0,0 +1,11 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(400000000000000000000000000000000000000000);
+type B = auto_sized_signed!(170141183460469231731687303715884105728);
+type C = auto_sized_int!(400000000000000000000000000000000000000000);
+
+fn main() {
+    let _ = auto_sized_unsigned_val!(400000000000000000000000000000000000000000);
+    let _ = auto_sized_signed_val!(-170141183460469231731687303715884105729);
+    let _ = auto_sized_int_val!(-400000000000000000000000000000000000000000);
+}
@@ -0,0 +1,9 @@
+use autosized_num::*;
+
+type A = auto_sized_pkg_version!(pre);
+type B = auto_sized_pkg_version!(revision);
+type C = auto_sized_pkg_version!(packed, 64, 64, 8);
+type D = auto_sized_pkg_version!(packed, 8, 0, 8);
+type E = auto_sized_pkg_version!(packed, 8, 8);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use autosized_num::*;
+
+type A = auto_sized_int!(+5i16);
+
+fn main() {}
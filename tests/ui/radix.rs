@@ -0,0 +1,8 @@
+use autosized_num::*;
+
+type A = auto_sized_radix!("1G4", 16);
+type B = auto_sized_radix!("101", 37);
+type C = auto_sized_radix!("", 10);
+type D = auto_sized_radix!("-__", 10);
+
+fn main() {}
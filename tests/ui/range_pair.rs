@@ -0,0 +1,6 @@
+use autosized_num::auto_sized_range;
+
+type A = auto_sized_range!(125, -40);
+type B = auto_sized_range!(0, 10, unsigned);
+
+fn main() {}
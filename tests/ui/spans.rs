@@ -0,0 +1,16 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned_all!(10, 300, 400000000000000000000000000000000000000000);
+type B = auto_sized_signed_all!(-200, x, 100);
+type C = auto_sized_or!(0x01, 400000000000000000000000000000000000000000);
+type D = auto_sized_range!(10..=5);
+type E = auto_sized_nonzero_unsigned!(0);
+type F = auto_sized_nonzero_signed!(0);
+type G = auto_sized_usize!(70000);
+type H = auto_sized_float!(1e400);
+type I = auto_sized_unsigned!(1 + 2 * (3 / 0));
+
+#[auto_size_consts(verbose)]
+mod m {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use autosized_num::*;
+
+auto_sized_struct! {
+    struct Overflow {
+        a: 400000000000000000000000000000000000000000,
+    }
+}
+
+auto_sized_struct! {
+    struct Tuple(300);
+}
+
+auto_sized_struct! {
+    struct Float {
+        a: 1.5,
+    }
+}
+
+fn main() {}
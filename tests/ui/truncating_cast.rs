@@ -0,0 +1,8 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(300 as u8);
+type B = auto_sized_signed!(-129 as i8);
+type C = auto_sized_int!(200 as i8);
+type D = auto_sized_unsigned!(1 as usize);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use autosized_num::*;
+
+type A = auto_from_twos_complement_type!(0x1_0000, 16);
+type B = auto_from_twos_complement_type!(0xFF, 12);
+type C = auto_from_twos_complement_type!(0xFF, 8, wide);
+
+fn main() {}
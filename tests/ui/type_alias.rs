@@ -0,0 +1,8 @@
+use autosized_num::*;
+
+auto_sized_type_alias!(Negative, -1);
+auto_sized_type_alias!(70000);
+auto_sized_signed_type_alias!(TooBig, u128::MAX);
+auto_sized_newtype!(Id, 300, serd);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(70000 => u16);
+type B = auto_sized_unsigned!(10 => u8, min = u16);
+type C = auto_sized_unsigned!(300 => i16);
+type D = auto_sized_unsigned!(300 =>);
+
+fn main() {
+    let _ = auto_sized_unsigned_val!(255 => u16);
+}
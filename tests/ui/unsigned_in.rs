@@ -0,0 +1,7 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned_in!([u8, u16], 70000);
+type B = auto_sized_unsigned_in!([u16, i32], 10);
+type C = auto_sized_unsigned_in!([], 10);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(1 == 1);
+type B = auto_sized_signed!(-(1 && 2));
+type C = auto_sized_int!(!0);
+type D = auto_sized_unsigned!(1 << x);
+type E = auto_sized_unsigned!(2 * 3 > 5);
+
+fn main() {
+    let f = auto_sized_int_val!(10 += 1);
+    let g = auto_sized_unsigned_val!(1.pow(2));
+}
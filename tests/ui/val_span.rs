@@ -0,0 +1,18 @@
+use autosized_num::*;
+
+macro_rules! small {
+    ($value:expr) => {
+        auto_sized_unsigned_val!($value)
+    };
+}
+
+macro_rules! offset {
+    ($value:literal) => {
+        auto_sized_int_val!($value)
+    };
+}
+
+fn main() {
+    let _: u8 = small!(300);
+    let _: i8 = offset!(-200);
+}
@@ -0,0 +1,43 @@
+use autosized_num::*;
+
+type A = auto_sized_unsigned!(18446744073709551616, max = u64);
+type B = auto_sized_unsigned!(300, max = u8);
+type C = auto_sized_unsigned!(300, max = u128);
+type D = auto_sized_unsigned!(10, min = u32, max = u16);
+type E = auto_sized_int!(300, ptr_width = 8);
+type F = auto_sized_int!(300, width = 32);
+type G = auto_sized_digits!(0);
+type H = auto_sized_digits!(39);
+type I = auto_sized_hex_digits!(33);
+type J = auto_sized_hex_digits!(0);
+type K = auto_sized_bits!(0);
+type L = auto_sized_bits!(129);
+type M = auto_sized_bytes!(17);
+type N = auto_sized_bytes!(3, exact);
+type O = auto_sized_bytes!(0);
+type P = auto_sized_for_bits!(129);
+type Q = auto_sized_for_bits_signed!(128);
+type R = auto_sized_for_bits_signed!(0);
+type X = auto_sized_flags!(0);
+type Y = auto_sized_flags!(129);
+type Z = auto_sized_unsigned!(300, max = u8, max = u64);
+type AA = auto_sized_unsigned!(0x0F, width_from_digits, native, width_from_digits);
+type AB = auto_sized_signed!(-5, min = i16, min = i32);
+type AC = auto_sized_int!(300, ptr_width = 32, ptr_width = 64);
+type S = auto_sized_signed!(-3_000_000_000, max = i32);
+type T = auto_sized_signed!(-200, max = u16);
+type U = auto_sized_int!(5_000_000_000, max = u32);
+type V = auto_sized_int!(-200, max = i128);
+type W = auto_sized_signed!(-5, min = u16);
+
+fn vals() {
+    let _ = auto_sized_signed_val!(200, max = i8);
+    let _ = auto_sized_int_val!(70_000, max = i16);
+    let _ = auto_sized_signed_val!(-5, min = i64, max = i32);
+    let _ = auto_sized_int_val!(5, min = u32, max = i16);
+    let _ = auto_sized_int_val!(300, min = u16, max = u8);
+    // Pointers on the test host are wider than the cap, so `native` falls back to `i8`.
+    let _: auto_sized_signed!(-10, native, max = i16) = 0isize;
+}
+
+fn main() {}
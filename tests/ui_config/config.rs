@@ -0,0 +1,11 @@
+use autosized_num::*;
+
+type A = auto_sized_from_toml!("../../../../tests/data/missing.toml", "network.max_payload");
+type B = auto_sized_from_toml!("../../../../tests/data/limits.toml", "network.timeout");
+type C = auto_sized_from_toml!("../../../../tests/data/limits.toml", "network.name");
+type D = auto_sized_from_json!("../../../../tests/data/limits.json", "network.max_payload.low");
+type E = auto_sized_from_json!("../../../../tests/data/limits.json", "network.skew", unsigned);
+type F = auto_sized_from_toml!("../../../../tests/data/limits.json", "network");
+type G = auto_sized_from_toml!("../../../../tests/data/limits.toml", "network.ratio");
+
+fn main() {}